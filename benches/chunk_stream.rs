@@ -0,0 +1,108 @@
+//! The throughput benchmark of decoding a synthetic stream of message chunks.
+//!
+//! Run with:
+//! ```shell
+//! cargo bench --bench chunk_stream
+//! ```
+//!
+//! The throughput should stay almost constant as the size of the stream grows,
+//! regardless of the fragment size, because the decoding is linear in time.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use clust::messages::StreamChunk;
+use clust::sse::SseDecoder;
+
+/// The number of iterations for each case.
+const ITERATIONS: u32 = 5;
+
+/// Builds a synthetic stream of `content_block_delta` events with the total size of at least `size` bytes.
+fn synthetic_stream(size: usize) -> Vec<u8> {
+    let mut source = String::with_capacity(size + 1024);
+    source.push_str("event: message_start\r\ndata: {\"type\": \"message_start\", \"message\": {\"id\": \"msg_01\", \"type\": \"message\", \"role\": \"assistant\", \"content\": [], \"model\": \"claude-3-opus-20240229\", \"stop_reason\": null, \"stop_sequence\": null, \"usage\": {\"input_tokens\": 25, \"output_tokens\": 1}}}\r\n\r\n");
+    source.push_str("event: content_block_start\r\ndata: {\"type\": \"content_block_start\", \"index\": 0, \"content_block\": {\"type\": \"text\", \"text\": \"\"}}\r\n\r\n");
+
+    let mut index = 0;
+    while source.len() < size {
+        source.push_str(&format!(
+            "event: content_block_delta\r\ndata: {{\"type\": \"content_block_delta\", \"index\": 0, \"delta\": {{\"type\": \"text_delta\", \"text\": \"token {} こんにちは\"}}}}\r\n\r\n",
+            index
+        ));
+        index += 1;
+    }
+
+    source.push_str("event: content_block_stop\r\ndata: {\"type\": \"content_block_stop\", \"index\": 0}\r\n\r\n");
+    source.push_str("event: message_stop\r\ndata: {\"type\": \"message_stop\"}\r\n\r\n");
+    source.into_bytes()
+}
+
+/// Builds a stream with a single event whose data line has `size` bytes.
+fn long_line_stream(size: usize) -> Vec<u8> {
+    format!(
+        "event: ping\r\ndata: {{\"type\": \"ping\", \"padding\": \"{}\"}}\r\n\r\n",
+        "x".repeat(size)
+    )
+    .into_bytes()
+}
+
+/// Decodes the source pushed in fragments and returns the number of chunks.
+fn decode(
+    source: &[u8],
+    fragment_size: usize,
+) -> usize {
+    let mut decoder = SseDecoder::new();
+    let mut count = 0;
+    for fragment in source.chunks(fragment_size) {
+        decoder.push(fragment);
+        while let Some(event) = decoder.next_event() {
+            let chunk = StreamChunk::from_event(&event).unwrap();
+            black_box(chunk);
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Measures the throughput in MiB/s.
+fn measure(
+    name: &str,
+    source: &[u8],
+    fragment_size: usize,
+) {
+    let mut elapsed = Duration::ZERO;
+    let mut count = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        count = decode(black_box(source), fragment_size);
+        elapsed += start.elapsed();
+    }
+
+    let mebibytes =
+        source.len() as f64 * ITERATIONS as f64 / (1024.0 * 1024.0);
+    println!(
+        "{:<12} {:>6.1} MiB  fragment {:>7} B  chunks {:>7}  {:>8.1} MiB/s",
+        name,
+        source.len() as f64 / (1024.0 * 1024.0),
+        fragment_size,
+        count,
+        mebibytes / elapsed.as_secs_f64(),
+    );
+}
+
+fn main() {
+    for size in [1, 4, 16] {
+        let source = synthetic_stream(size * 1024 * 1024);
+        // A whole burst, network-sized fragments and tiny fragments.
+        for fragment_size in [source.len(), 16 * 1024, 7] {
+            measure("deltas", &source, fragment_size);
+        }
+    }
+
+    for size in [1, 4, 16] {
+        let source = long_line_stream(size * 1024 * 1024);
+        for fragment_size in [16 * 1024, 1024] {
+            measure("long line", &source, fragment_size);
+        }
+    }
+}
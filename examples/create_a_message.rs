@@ -39,7 +39,7 @@ async fn main() -> anyhow::Result<()> {
     let messages = vec![Message::user(
         arguments.message,
     )];
-    let max_tokens = MaxTokens::new(1024, model)?;
+    let max_tokens = MaxTokens::new(1024, &model)?;
     let system_prompt = SystemPrompt::new(arguments.prompt);
     let request_body = MessagesRequestBody {
         model,
@@ -13,7 +13,7 @@ use clust::messages::Message;
 use clust::messages::MessagesRequestBody;
 use clust::messages::SystemPrompt;
 use clust::messages::{ClaudeModel, StreamOption};
-use clust::messages::{MaxTokens, StreamChunk};
+use clust::messages::{MaxTokens, MessageAccumulator};
 use clust::Client;
 
 use clap::Parser;
@@ -40,7 +40,7 @@ async fn main() -> anyhow::Result<()> {
     let messages = vec![Message::user(
         arguments.message,
     )];
-    let max_tokens = MaxTokens::new(1024, model)?;
+    let max_tokens = MaxTokens::new(1024, &model)?;
     let system_prompt = SystemPrompt::new(arguments.prompt);
     let request_body = MessagesRequestBody {
         model,
@@ -56,7 +56,7 @@ async fn main() -> anyhow::Result<()> {
         .create_a_message_stream(request_body)
         .await?;
 
-    let mut buffer = String::new();
+    let mut accumulator = MessageAccumulator::new();
 
     // 4. Poll the stream.
     // NOTE: The `tokio_stream::StreamExt` run on the `tokio` runtime.
@@ -64,12 +64,8 @@ async fn main() -> anyhow::Result<()> {
         match chunk {
             | Ok(chunk) => {
                 println!("Chunk:\n{}", chunk);
-                match chunk {
-                    | StreamChunk::ContentBlockDelta(content_block_delta) => {
-                        buffer.push_str(&content_block_delta.delta.text);
-                    },
-                    | _ => {},
-                }
+                // Accumulate the chunk into the message.
+                accumulator.push(&chunk)?;
             },
             | Err(error) => {
                 eprintln!("Chunk error:\n{:?}", error);
@@ -77,7 +73,7 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    println!("Result:\n{}", buffer);
+    println!("Result:\n{}", accumulator.into_response()?);
 
     Ok(())
 }
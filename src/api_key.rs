@@ -28,6 +28,16 @@ impl ApiKey {
     }
 }
 
+impl std::fmt::Debug for ApiKey {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        // Do not leak the API key in logs.
+        f.write_str("ApiKey(***)")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,185 @@
+use std::env::VarError;
+use std::fmt::Display;
+
+use crate::ClientError;
+
+/// The base URL of the Anthropic API.
+///
+/// All endpoints are resolved relative to this URL, e.g. `{base_url}/v1/messages`.
+/// It can point to a proxy, a gateway or a local mock server instead of the official API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BaseUrl {
+    value: String,
+}
+
+impl Default for BaseUrl {
+    fn default() -> Self {
+        Self::new("https://api.anthropic.com")
+    }
+}
+
+impl Display for BaseUrl {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl From<&str> for BaseUrl {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for BaseUrl {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl BaseUrl {
+    /// Creates a new base URL.
+    ///
+    /// Trailing slashes are removed, so both `https://example.com/proxy` and `https://example.com/proxy/` are accepted.
+    pub fn new<S>(value: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let value: String = value.into();
+        Self {
+            value: value
+                .trim_end_matches('/')
+                .to_string(),
+        }
+    }
+
+    /// Loads the base URL from the environment variable: `ANTHROPIC_BASE_URL`.
+    pub fn from_env() -> Result<Self, VarError> {
+        let value = std::env::var("ANTHROPIC_BASE_URL")?;
+        Ok(Self::new(value))
+    }
+
+    /// Joins the endpoint path to the base URL.
+    pub(crate) fn join(
+        &self,
+        endpoint: &str,
+    ) -> String {
+        format!(
+            "{}/{}",
+            self.value,
+            endpoint.trim_start_matches('/')
+        )
+    }
+}
+
+/// The characters percent-encoded in a path segment, i.e. all except the unreserved characters.
+const PATH_SEGMENT: &percent_encoding::AsciiSet =
+    &percent_encoding::NON_ALPHANUMERIC
+        .remove(b'-')
+        .remove(b'.')
+        .remove(b'_')
+        .remove(b'~');
+
+/// Percent-encodes a caller-supplied ID as a path segment, so that `/`, `?` or `#` can not change the request target.
+///
+/// ## Arguments
+/// - `segment` - The path segment, e.g. a model ID.
+///
+/// ## Errors
+/// It returns [`ClientError::InvalidPathSegment`] for the dot segments `.` and `..`,
+/// which URL normalization resolves even if percent-encoded as `%2E`.
+pub(crate) fn encode_path_segment(
+    segment: &str
+) -> Result<String, ClientError> {
+    if segment == "." || segment == ".." {
+        return Err(ClientError::InvalidPathSegment(
+            segment.to_string(),
+        ));
+    }
+
+    Ok(
+        percent_encoding::utf8_percent_encode(segment, PATH_SEGMENT)
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let base_url = BaseUrl::new("https://example.com");
+        assert_eq!(base_url.value, "https://example.com");
+
+        let base_url = BaseUrl::new("https://example.com/proxy/");
+        assert_eq!(
+            base_url.value,
+            "https://example.com/proxy"
+        );
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            BaseUrl::default().value,
+            "https://api.anthropic.com"
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            BaseUrl::new("https://example.com/").to_string(),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn join() {
+        assert_eq!(
+            BaseUrl::default().join("/v1/messages"),
+            "https://api.anthropic.com/v1/messages"
+        );
+        assert_eq!(
+            BaseUrl::new("https://example.com/proxy/").join("v1/messages"),
+            "https://example.com/proxy/v1/messages"
+        );
+        assert_eq!(
+            BaseUrl::new("http://localhost:8080").join("/v1/messages"),
+            "http://localhost:8080/v1/messages"
+        );
+    }
+
+    #[test]
+    fn encode_path_segment() {
+        assert_eq!(
+            super::encode_path_segment("claude-3-5-sonnet-20241022").unwrap(),
+            "claude-3-5-sonnet-20241022"
+        );
+        assert_eq!(
+            super::encode_path_segment("msgbatch_01.a~b").unwrap(),
+            "msgbatch_01.a~b"
+        );
+        assert_eq!(
+            super::encode_path_segment("../id?x=1#y z").unwrap(),
+            "..%2Fid%3Fx%3D1%23y%20z"
+        );
+        assert_eq!(
+            super::encode_path_segment("...").unwrap(),
+            "..."
+        );
+
+        // The dot segments would be resolved by URL normalization.
+        assert!(matches!(
+            super::encode_path_segment("."),
+            Err(ClientError::InvalidPathSegment(_))
+        ));
+        assert!(matches!(
+            super::encode_path_segment(".."),
+            Err(ClientError::InvalidPathSegment(_))
+        ));
+    }
+}
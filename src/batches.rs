@@ -0,0 +1,28 @@
+//! The [Message Batches API](https://docs.anthropic.com/en/api/creating-message-batches) implementations.
+
+mod create_message_batch_request_body;
+mod error;
+mod message_batch;
+mod message_batch_result;
+mod result;
+mod results_stream;
+
+pub(crate) mod api;
+
+pub use create_message_batch_request_body::CreateMessageBatchRequestBody;
+pub use create_message_batch_request_body::MessageBatchRequest;
+pub use error::BatchResultsStreamError;
+pub use error::BatchesError;
+pub use message_batch::MessageBatch;
+pub use message_batch::MessageBatchObjectType;
+pub use message_batch::ProcessingStatus;
+pub use message_batch::RequestCounts;
+pub use message_batch_result::CanceledMessageBatchResult;
+pub use message_batch_result::ErroredMessageBatchResult;
+pub use message_batch_result::ExpiredMessageBatchResult;
+pub use message_batch_result::MessageBatchIndividualResponse;
+pub use message_batch_result::MessageBatchResult;
+pub use message_batch_result::MessageBatchResultType;
+pub use message_batch_result::SucceededMessageBatchResult;
+pub use result::BatchResultsStreamResult;
+pub use result::BatchesResult;
@@ -0,0 +1,98 @@
+use crate::base_url::encode_path_segment;
+use crate::batches::results_stream::ResultsStream;
+use crate::batches::{
+    BatchResultsStreamResult, BatchesResult, CreateMessageBatchRequestBody,
+    MessageBatch,
+};
+use crate::response::{read_error_response, read_response};
+use crate::Client;
+use crate::{Page, PaginationQuery};
+use futures_core::Stream;
+
+pub(crate) async fn create_a_message_batch(
+    client: &Client,
+    request_body: CreateMessageBatchRequestBody,
+) -> BatchesResult<MessageBatch> {
+    // Send the request.
+    let response = client
+        .send(
+            client
+                .post("/v1/messages/batches")
+                .json(&request_body),
+        )
+        .await?;
+
+    read_response(response).await
+}
+
+pub(crate) async fn retrieve_a_message_batch(
+    client: &Client,
+    message_batch_id: &str,
+) -> BatchesResult<MessageBatch> {
+    // Send the request.
+    let response = client
+        .send(client.get(&format!(
+            "/v1/messages/batches/{}",
+            encode_path_segment(message_batch_id)?
+        )))
+        .await?;
+
+    read_response(response).await
+}
+
+pub(crate) async fn list_message_batches(
+    client: &Client,
+    query: PaginationQuery,
+) -> BatchesResult<Page<MessageBatch>> {
+    // Send the request.
+    let response = client
+        .send(
+            client
+                .get("/v1/messages/batches")
+                .query(&query),
+        )
+        .await?;
+
+    read_response(response).await
+}
+
+pub(crate) async fn cancel_a_message_batch(
+    client: &Client,
+    message_batch_id: &str,
+) -> BatchesResult<MessageBatch> {
+    // Send the request.
+    let response = client
+        .send(client.post(&format!(
+            "/v1/messages/batches/{}/cancel",
+            encode_path_segment(message_batch_id)?
+        )))
+        .await?;
+
+    read_response(response).await
+}
+
+pub(crate) async fn retrieve_message_batch_results(
+    client: &Client,
+    message_batch_id: &str,
+) -> BatchesResult<impl Stream<Item = BatchResultsStreamResult>> {
+    // Send the request.
+    let response = client
+        .send(client.get_stream(&format!(
+            "/v1/messages/batches/{}/results",
+            encode_path_segment(message_batch_id)?
+        )))
+        .await?;
+
+    // Check the response status code.
+    let status_code = response.status();
+
+    // Ok
+    if status_code.is_success() {
+        // Create a results stream from response bytes stream.
+        Ok(ResultsStream::new(response.bytes_stream()))
+    }
+    // Error
+    else {
+        Err(read_error_response(response).await)
+    }
+}
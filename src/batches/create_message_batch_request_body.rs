@@ -0,0 +1,93 @@
+use crate::macros::impl_display_for_serialize;
+use crate::messages::MessagesRequestBody;
+
+/// The request body to create a message batch.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct CreateMessageBatchRequestBody {
+    /// List of requests for prompt completion.
+    ///
+    /// Each is an individual request to create a message.
+    pub requests: Vec<MessageBatchRequest>,
+}
+
+impl_display_for_serialize!(CreateMessageBatchRequestBody);
+
+impl CreateMessageBatchRequestBody {
+    /// Creates a new request body to create a message batch.
+    ///
+    /// ## Arguments
+    /// - `requests` - The requests for prompt completion.
+    pub fn new(requests: Vec<MessageBatchRequest>) -> Self {
+        Self {
+            requests,
+        }
+    }
+}
+
+/// The individual request in a message batch.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageBatchRequest {
+    /// Developer-provided ID created for each request in a message batch.
+    ///
+    /// It is useful for matching results to requests, as results may be given out of request order.
+    /// It must be unique for each request within the message batch.
+    pub custom_id: String,
+    /// Messages API creation parameters for the individual request.
+    ///
+    /// The `stream` option is not supported in a message batch.
+    pub params: MessagesRequestBody,
+}
+
+impl_display_for_serialize!(MessageBatchRequest);
+
+impl MessageBatchRequest {
+    /// Creates a new individual request.
+    ///
+    /// ## Arguments
+    /// - `custom_id` - The developer-provided ID of the request.
+    /// - `params` - The request body of the Messages API.
+    pub fn new<S>(
+        custom_id: S,
+        params: MessagesRequestBody,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            custom_id: custom_id.into(),
+            params,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{ClaudeModel, MaxTokens, Message};
+
+    #[test]
+    fn serialize() {
+        let request_body =
+            CreateMessageBatchRequestBody::new(vec![MessageBatchRequest::new(
+                "request-1",
+                MessagesRequestBody {
+                    model: ClaudeModel::Claude3Haiku20240307,
+                    messages: vec![Message::user("Hello")],
+                    max_tokens: MaxTokens::new(
+                        16,
+                        &ClaudeModel::Claude3Haiku20240307,
+                    )
+                    .unwrap(),
+                    ..Default::default()
+                },
+            )]);
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"requests":[{"custom_id":"request-1","params":{"model":"claude-3-haiku-20240307","messages":[{"role":"user","content":"Hello"}],"max_tokens":16}}]}"#
+        );
+    }
+}
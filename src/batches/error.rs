@@ -0,0 +1,26 @@
+use crate::{ApiError, ClientError};
+
+/// The error type for the message batches API.
+#[derive(Debug, thiserror::Error)]
+pub enum BatchesError {
+    /// The client error.
+    #[error(transparent)]
+    ClientError(#[from] ClientError),
+    /// The API error.
+    #[error(transparent)]
+    ApiError(#[from] ApiError),
+}
+
+/// The error type for the stream of message batch results.
+#[derive(Debug, thiserror::Error)]
+pub enum BatchResultsStreamError {
+    /// Reqwest error.
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+    /// Failed to deserialize a line of the results.
+    #[error("Failed to deserialize result line as JSON: {error:?}, {line:?}")]
+    LineDeserializationFailed {
+        error: serde_json::Error,
+        line: String,
+    },
+}
@@ -0,0 +1,193 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+};
+use std::fmt::Display;
+
+/// The message batch object.
+///
+/// See also [the message batches API reference](https://docs.anthropic.com/en/api/messages-batch-examples).
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageBatch {
+    /// Unique object identifier.
+    pub id: String,
+    /// Object type. For message batches, this is always "message_batch".
+    #[serde(rename = "type")]
+    pub _type: MessageBatchObjectType,
+    /// Processing status of the message batch.
+    pub processing_status: ProcessingStatus,
+    /// Tallies requests within the message batch, categorized by their status.
+    pub request_counts: RequestCounts,
+    /// RFC 3339 datetime string representing the time at which processing for the message batch ended.
+    pub ended_at: Option<String>,
+    /// RFC 3339 datetime string representing the time at which the message batch was created.
+    pub created_at: String,
+    /// RFC 3339 datetime string representing the time at which the message batch will expire and end processing.
+    pub expires_at: String,
+    /// RFC 3339 datetime string representing the time at which the message batch was archived and its results became unavailable.
+    #[serde(default)]
+    pub archived_at: Option<String>,
+    /// RFC 3339 datetime string representing the time at which cancellation was initiated for the message batch.
+    pub cancel_initiated_at: Option<String>,
+    /// URL to a `.jsonl` file containing the results of the message batch requests.
+    ///
+    /// It is available only after the message batch has ended.
+    pub results_url: Option<String>,
+}
+
+impl_display_for_serialize!(MessageBatch);
+
+/// The object type of message batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageBatchObjectType {
+    /// message_batch
+    MessageBatch,
+}
+
+impl Default for MessageBatchObjectType {
+    fn default() -> Self {
+        Self::MessageBatch
+    }
+}
+
+impl Display for MessageBatchObjectType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | MessageBatchObjectType::MessageBatch => {
+                write!(f, "message_batch")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    MessageBatchObjectType,
+    MessageBatch => "message_batch"
+);
+
+/// The processing status of message batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessingStatus {
+    /// The message batch is being processed.
+    InProgress,
+    /// The cancellation of the message batch has been initiated.
+    Canceling,
+    /// The processing of the message batch has ended.
+    Ended,
+}
+
+impl Default for ProcessingStatus {
+    fn default() -> Self {
+        Self::InProgress
+    }
+}
+
+impl Display for ProcessingStatus {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ProcessingStatus::InProgress => {
+                write!(f, "in_progress")
+            },
+            | ProcessingStatus::Canceling => {
+                write!(f, "canceling")
+            },
+            | ProcessingStatus::Ended => {
+                write!(f, "ended")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ProcessingStatus,
+    InProgress => "in_progress",
+    Canceling => "canceling",
+    Ended => "ended"
+);
+
+/// The tallies of requests within the message batch, categorized by their status.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct RequestCounts {
+    /// Number of requests in the message batch that are processing.
+    pub processing: u32,
+    /// Number of requests in the message batch that have completed successfully.
+    pub succeeded: u32,
+    /// Number of requests in the message batch that encountered an error.
+    pub errored: u32,
+    /// Number of requests in the message batch that have been canceled.
+    pub canceled: u32,
+    /// Number of requests in the message batch that have expired.
+    pub expired: u32,
+}
+
+impl_display_for_serialize!(RequestCounts);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_processing_status() {
+        assert_eq!(
+            ProcessingStatus::InProgress.to_string(),
+            "in_progress"
+        );
+        assert_eq!(
+            ProcessingStatus::Canceling.to_string(),
+            "canceling"
+        );
+        assert_eq!(
+            ProcessingStatus::Ended.to_string(),
+            "ended"
+        );
+    }
+
+    #[test]
+    fn deserialize_message_batch() {
+        let message_batch = MessageBatch {
+            id: "msgbatch_01".to_string(),
+            _type: MessageBatchObjectType::MessageBatch,
+            processing_status: ProcessingStatus::Ended,
+            request_counts: RequestCounts {
+                processing: 0,
+                succeeded: 99,
+                errored: 1,
+                canceled: 0,
+                expired: 0,
+            },
+            ended_at: Some("2024-09-24T18:39:03.114875Z".to_string()),
+            created_at: "2024-09-24T18:37:24.100435Z".to_string(),
+            expires_at: "2024-09-25T18:37:24.100435Z".to_string(),
+            archived_at: None,
+            cancel_initiated_at: None,
+            results_url: Some(
+                "https://api.anthropic.com/v1/messages/batches/msgbatch_01/results"
+                    .to_string(),
+            ),
+        };
+        assert_eq!(
+            serde_json::from_str::<MessageBatch>(
+                r#"{"id":"msgbatch_01","type":"message_batch","processing_status":"ended","request_counts":{"processing":0,"succeeded":99,"errored":1,"canceled":0,"expired":0},"ended_at":"2024-09-24T18:39:03.114875Z","created_at":"2024-09-24T18:37:24.100435Z","expires_at":"2024-09-25T18:37:24.100435Z","cancel_initiated_at":null,"results_url":"https://api.anthropic.com/v1/messages/batches/msgbatch_01/results"}"#
+            )
+            .unwrap(),
+            message_batch
+        );
+    }
+}
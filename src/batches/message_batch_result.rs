@@ -0,0 +1,253 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+    impl_enum_struct_serialization,
+};
+use crate::messages::MessagesResponseBody;
+use crate::ApiErrorResponse;
+use std::fmt::Display;
+
+/// The individual response in the results of a message batch.
+///
+/// It is a line of the `.jsonl` results file.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageBatchIndividualResponse {
+    /// Developer-provided ID of the request.
+    pub custom_id: String,
+    /// Processing result for this request.
+    pub result: MessageBatchResult,
+}
+
+impl_display_for_serialize!(MessageBatchIndividualResponse);
+
+/// The processing result of an individual request in a message batch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageBatchResult {
+    /// The request was processed successfully.
+    Succeeded(SucceededMessageBatchResult),
+    /// The request encountered an error and the message was not created.
+    Errored(ErroredMessageBatchResult),
+    /// The message batch was canceled before the request was sent to the model.
+    Canceled(CanceledMessageBatchResult),
+    /// The message batch reached its expiration before the request was sent to the model.
+    Expired(ExpiredMessageBatchResult),
+}
+
+impl Default for MessageBatchResult {
+    fn default() -> Self {
+        Self::Succeeded(SucceededMessageBatchResult::default())
+    }
+}
+
+impl_enum_struct_serialization!(
+    MessageBatchResult,
+    type,
+    Succeeded(SucceededMessageBatchResult, "succeeded"),
+    Errored(ErroredMessageBatchResult, "errored"),
+    Canceled(CanceledMessageBatchResult, "canceled"),
+    Expired(ExpiredMessageBatchResult, "expired")
+);
+
+impl_display_for_serialize!(MessageBatchResult);
+
+impl MessageBatchResult {
+    /// Returns the message if the request was processed successfully.
+    pub fn message(&self) -> Option<&MessagesResponseBody> {
+        match self {
+            | MessageBatchResult::Succeeded(succeeded) => {
+                Some(&succeeded.message)
+            },
+            | _ => None,
+        }
+    }
+}
+
+/// The result of a request processed successfully.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct SucceededMessageBatchResult {
+    /// The result type. It is always `succeeded`.
+    #[serde(rename = "type")]
+    pub _type: MessageBatchResultType,
+    /// The created message.
+    pub message: MessagesResponseBody,
+}
+
+impl_display_for_serialize!(SucceededMessageBatchResult);
+
+/// The result of a request that encountered an error.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ErroredMessageBatchResult {
+    /// The result type. It is always `errored`.
+    #[serde(rename = "type")]
+    pub _type: MessageBatchResultType,
+    /// The error response.
+    pub error: ApiErrorResponse,
+}
+
+impl_display_for_serialize!(ErroredMessageBatchResult);
+
+/// The result of a request canceled before it was sent to the model.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CanceledMessageBatchResult {
+    /// The result type. It is always `canceled`.
+    #[serde(rename = "type")]
+    pub _type: MessageBatchResultType,
+}
+
+impl Default for CanceledMessageBatchResult {
+    fn default() -> Self {
+        Self {
+            _type: MessageBatchResultType::Canceled,
+        }
+    }
+}
+
+impl_display_for_serialize!(CanceledMessageBatchResult);
+
+/// The result of a request expired before it was sent to the model.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ExpiredMessageBatchResult {
+    /// The result type. It is always `expired`.
+    #[serde(rename = "type")]
+    pub _type: MessageBatchResultType,
+}
+
+impl Default for ExpiredMessageBatchResult {
+    fn default() -> Self {
+        Self {
+            _type: MessageBatchResultType::Expired,
+        }
+    }
+}
+
+impl_display_for_serialize!(ExpiredMessageBatchResult);
+
+/// The type of the individual result in a message batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageBatchResultType {
+    /// succeeded
+    Succeeded,
+    /// errored
+    Errored,
+    /// canceled
+    Canceled,
+    /// expired
+    Expired,
+}
+
+impl Default for MessageBatchResultType {
+    fn default() -> Self {
+        Self::Succeeded
+    }
+}
+
+impl Display for MessageBatchResultType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | MessageBatchResultType::Succeeded => {
+                write!(f, "succeeded")
+            },
+            | MessageBatchResultType::Errored => {
+                write!(f, "errored")
+            },
+            | MessageBatchResultType::Canceled => {
+                write!(f, "canceled")
+            },
+            | MessageBatchResultType::Expired => {
+                write!(f, "expired")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    MessageBatchResultType,
+    Succeeded => "succeeded",
+    Errored => "errored",
+    Canceled => "canceled",
+    Expired => "expired"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{ClaudeModel, Role, StopReason, Usage};
+
+    #[test]
+    fn deserialize_succeeded() {
+        let response = serde_json::from_str::<MessageBatchIndividualResponse>(
+            r#"{"custom_id":"request-1","result":{"type":"succeeded","message":{"id":"msg_01","type":"message","role":"assistant","content":[{"type":"text","text":"Hello!"}],"model":"claude-3-haiku-20240307","stop_reason":"end_turn","stop_sequence":null,"usage":{"input_tokens":10,"output_tokens":3}}}}"#,
+        )
+        .unwrap();
+        assert_eq!(response.custom_id, "request-1");
+
+        let message = response.result.message().unwrap();
+        assert_eq!(message.id, "msg_01");
+        assert_eq!(message.role, Role::Assistant);
+        assert_eq!(
+            message.model,
+            ClaudeModel::Claude3Haiku20240307
+        );
+        assert_eq!(
+            message.stop_reason,
+            Some(StopReason::EndTurn)
+        );
+        assert_eq!(
+            message.usage,
+            Usage {
+                input_tokens: 10,
+                output_tokens: 3,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_errored() {
+        let response = serde_json::from_str::<MessageBatchIndividualResponse>(
+            r#"{"custom_id":"request-2","result":{"type":"errored","error":{"type":"error","error":{"type":"invalid_request_error","message":"max_tokens: 100000 > 4096"}}}}"#,
+        )
+        .unwrap();
+        match response.result {
+            | MessageBatchResult::Errored(errored) => {
+                assert_eq!(
+                    errored.error.error._type,
+                    "invalid_request_error"
+                );
+            },
+            | _ => panic!("unexpected result"),
+        }
+    }
+
+    #[test]
+    fn deserialize_canceled_and_expired() {
+        assert_eq!(
+            serde_json::from_str::<MessageBatchResult>(r#"{"type":"canceled"}"#)
+                .unwrap(),
+            MessageBatchResult::Canceled(CanceledMessageBatchResult::default())
+        );
+        assert_eq!(
+            serde_json::from_str::<MessageBatchResult>(r#"{"type":"expired"}"#)
+                .unwrap(),
+            MessageBatchResult::Expired(ExpiredMessageBatchResult::default())
+        );
+    }
+
+    #[test]
+    fn serialize_canceled() {
+        assert_eq!(
+            serde_json::to_string(&MessageBatchResult::Canceled(
+                CanceledMessageBatchResult::default()
+            ))
+            .unwrap(),
+            r#"{"type":"canceled"}"#
+        );
+    }
+}
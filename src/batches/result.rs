@@ -0,0 +1,10 @@
+use crate::batches::{
+    BatchResultsStreamError, BatchesError, MessageBatchIndividualResponse,
+};
+
+/// The result type for the message batches API.
+pub type BatchesResult<T> = Result<T, BatchesError>;
+
+/// The result type as stream item for the message batch results.
+pub type BatchResultsStreamResult =
+    Result<MessageBatchIndividualResponse, BatchResultsStreamError>;
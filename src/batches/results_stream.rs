@@ -0,0 +1,187 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, BytesMut};
+use futures_core::{ready, Stream};
+use pin_project::pin_project;
+
+use crate::batches::{BatchResultsStreamError, BatchResultsStreamResult};
+
+/// The stream item of the reqwest response.
+type ReqwestStreamItem = Result<bytes::Bytes, reqwest::Error>;
+
+/// The stream of message batch results decoded from the `.jsonl` results file.
+#[pin_project]
+pub(crate) struct ResultsStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    #[pin]
+    stream: S,
+    /// The bytes that have not been decoded yet.
+    buffer: BytesMut,
+    /// The length of the buffer already scanned for a line feed.
+    scanned: usize,
+    /// Whether the inner stream has ended.
+    finished: bool,
+}
+
+impl<S> ResultsStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    /// Create a new results stream.
+    pub fn new(stream: S) -> Self {
+        ResultsStream {
+            stream,
+            buffer: BytesMut::new(),
+            scanned: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<S> Stream for ResultsStream<S>
+where
+    S: Stream<Item = ReqwestStreamItem> + Unpin,
+{
+    type Item = BatchResultsStreamResult;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // Take the next line, or the remaining bytes at the end of the stream.
+            let line = match this.buffer[*this.scanned..]
+                .iter()
+                .position(|b| *b == b'\n')
+            {
+                | Some(position) => {
+                    let line = this
+                        .buffer
+                        .split_to(*this.scanned + position);
+                    this.buffer.advance(1);
+                    *this.scanned = 0;
+                    Some(line)
+                },
+                | None if *this.finished => {
+                    if this.buffer.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    *this.scanned = 0;
+                    Some(this.buffer.split())
+                },
+                | None => {
+                    *this.scanned = this.buffer.len();
+                    None
+                },
+            };
+
+            if let Some(line) = line {
+                let line = line
+                    .strip_suffix(b"\r")
+                    .unwrap_or(&line[..]);
+
+                // Skip blank lines.
+                if line
+                    .iter()
+                    .all(u8::is_ascii_whitespace)
+                {
+                    continue;
+                }
+
+                return Poll::Ready(Some(
+                    serde_json::from_slice(line).map_err(|error| {
+                        BatchResultsStreamError::LineDeserializationFailed {
+                            error,
+                            line: String::from_utf8_lossy(line).into_owned(),
+                        }
+                    }),
+                ));
+            }
+
+            match ready!(this
+                .stream
+                .as_mut()
+                .poll_next(cx))
+            {
+                // The stream has more data.
+                | Some(Ok(chunk)) => {
+                    this.buffer.extend_from_slice(&chunk);
+                },
+                // The stream has an error.
+                | Some(Err(error)) => {
+                    return Poll::Ready(Some(Err(
+                        BatchResultsStreamError::ReqwestError(error),
+                    )));
+                },
+                // The stream has no more data.
+                | None => {
+                    *this.finished = true;
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batches::MessageBatchResult;
+
+    #[tokio::test]
+    async fn next() {
+        use futures_util::StreamExt;
+
+        let input_stream = futures_util::stream::iter(vec![
+            Ok(bytes::Bytes::from(
+                "{\"custom_id\":\"request-1\",\"result\":{\"type\":\"canceled\"}}\r\n{\"custom_id\":\"req",
+            )),
+            Ok(bytes::Bytes::from(
+                "uest-2\",\"result\":{\"type\":\"expired\"}}\n\n{\"custom_id\":\"request-3\",\"result\":{\"type\":\"canceled\"}}",
+            )),
+        ]);
+
+        let mut stream = ResultsStream::new(input_stream);
+
+        let response = stream.next().await.unwrap().unwrap();
+        assert_eq!(response.custom_id, "request-1");
+        assert!(matches!(
+            response.result,
+            MessageBatchResult::Canceled(_)
+        ));
+
+        let response = stream.next().await.unwrap().unwrap();
+        assert_eq!(response.custom_id, "request-2");
+        assert!(matches!(
+            response.result,
+            MessageBatchResult::Expired(_)
+        ));
+
+        // The last line without a line feed.
+        let response = stream.next().await.unwrap().unwrap();
+        assert_eq!(response.custom_id, "request-3");
+
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn next_with_invalid_line() {
+        use futures_util::StreamExt;
+
+        let input_stream = futures_util::stream::iter(vec![Ok(
+            bytes::Bytes::from("{\"custom_id\":\n"),
+        )]);
+
+        let mut stream = ResultsStream::new(input_stream);
+
+        assert!(matches!(
+            stream.next().await.unwrap(),
+            Err(BatchResultsStreamError::LineDeserializationFailed { .. })
+        ));
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt::Display;
+
+use crate::macros::impl_enum_string_conversion;
+
+/// The beta feature of the API opted into by the `anthropic-beta` header.
+///
+/// See also [beta headers](https://docs.anthropic.com/en/api/beta-headers).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BetaFeature {
+    /// prompt-caching-2024-07-31
+    PromptCaching20240731,
+    /// message-batches-2024-09-24
+    MessageBatches20240924,
+    /// token-counting-2024-11-01
+    TokenCounting20241101,
+    /// pdfs-2024-09-25
+    Pdfs20240925,
+    /// max-tokens-3-5-sonnet-2024-07-15
+    MaxTokens35Sonnet20240715,
+    /// computer-use-2024-10-22
+    ComputerUse20241022,
+    /// output-128k-2025-02-19
+    Output128k20250219,
+    /// interleaved-thinking-2025-05-14
+    InterleavedThinking20250514,
+    /// Any beta feature unknown to this crate, e.g. a feature newer than this crate.
+    Custom(String),
+}
+
+impl_enum_string_conversion!(
+    BetaFeature,
+    PromptCaching20240731 => "prompt-caching-2024-07-31",
+    MessageBatches20240924 => "message-batches-2024-09-24",
+    TokenCounting20241101 => "token-counting-2024-11-01",
+    Pdfs20240925 => "pdfs-2024-09-25",
+    MaxTokens35Sonnet20240715 => "max-tokens-3-5-sonnet-2024-07-15",
+    ComputerUse20241022 => "computer-use-2024-10-22",
+    Output128k20250219 => "output-128k-2025-02-19",
+    InterleavedThinking20250514 => "interleaved-thinking-2025-05-14";
+    Custom
+);
+
+impl BetaFeature {
+    /// Creates a beta feature from the header value, e.g. `pdfs-2024-09-25`.
+    ///
+    /// A known value is parsed into the corresponding variant, otherwise [`BetaFeature::Custom`].
+    ///
+    /// ## Arguments
+    /// - `value` - The value of the beta feature.
+    pub fn new<S>(value: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::from(value.into())
+    }
+
+    /// Maps a custom beta feature with a known value to the known beta feature.
+    fn normalize(self) -> Self {
+        match self {
+            | Self::Custom(value) => Self::from(value),
+            | known => known,
+        }
+    }
+}
+
+/// The set of beta features sent as the comma-separated `anthropic-beta` header.
+///
+/// ## Example
+/// ```
+/// use clust::{BetaFeature, BetaFeatures};
+///
+/// let beta_features = BetaFeatures::new()
+///     .with(BetaFeature::Pdfs20240925)
+///     .with("new-feature-2099-01-01");
+/// assert_eq!(beta_features.to_string(), "pdfs-2024-09-25,new-feature-2099-01-01");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BetaFeatures {
+    values: Vec<BetaFeature>,
+}
+
+impl Display for BetaFeatures {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        for (index, value) in self.values.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> FromIterator<T> for BetaFeatures
+where
+    T: Into<BetaFeature>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter()
+            .fold(Self::new(), Self::with)
+    }
+}
+
+impl From<BetaFeature> for BetaFeatures {
+    fn from(value: BetaFeature) -> Self {
+        Self::new().with(value)
+    }
+}
+
+impl From<Vec<BetaFeature>> for BetaFeatures {
+    fn from(values: Vec<BetaFeature>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl BetaFeatures {
+    /// Creates an empty set of beta features.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the beta feature, which is ignored if it is already included.
+    ///
+    /// A [`BetaFeature::Custom`] with a known value is added as the known beta feature.
+    ///
+    /// ## Arguments
+    /// - `feature` - The beta feature.
+    pub fn with<T>(
+        mut self,
+        feature: T,
+    ) -> Self
+    where
+        T: Into<BetaFeature>,
+    {
+        let feature = feature.into().normalize();
+        if !self.values.contains(&feature) {
+            self.values.push(feature);
+        }
+        self
+    }
+
+    /// Returns whether the beta feature is included.
+    pub fn contains(
+        &self,
+        feature: &BetaFeature,
+    ) -> bool {
+        self.values
+            .contains(&feature.clone().normalize())
+    }
+
+    /// Returns whether no beta feature is included.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the iterator of the beta features.
+    pub fn iter(&self) -> impl Iterator<Item = &BetaFeature> {
+        self.values.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            BetaFeature::new("pdfs-2024-09-25"),
+            BetaFeature::Pdfs20240925
+        );
+        assert_eq!(
+            BetaFeature::new("new-feature"),
+            BetaFeature::Custom("new-feature".to_string())
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            BetaFeature::PromptCaching20240731.to_string(),
+            "prompt-caching-2024-07-31"
+        );
+        assert_eq!(
+            BetaFeature::Custom("new-feature".to_string()).to_string(),
+            "new-feature"
+        );
+    }
+
+    #[test]
+    fn beta_features() {
+        let beta_features = BetaFeatures::new()
+            .with(BetaFeature::TokenCounting20241101)
+            .with("pdfs-2024-09-25")
+            .with(BetaFeature::Pdfs20240925);
+        assert_eq!(
+            beta_features.to_string(),
+            "token-counting-2024-11-01,pdfs-2024-09-25"
+        );
+        assert!(beta_features.contains(&BetaFeature::Pdfs20240925));
+        assert!(beta_features.contains(&BetaFeature::Custom(
+            "pdfs-2024-09-25".to_string()
+        )));
+        assert!(!beta_features.is_empty());
+        assert!(BetaFeatures::new().is_empty());
+        assert_eq!(BetaFeatures::new().to_string(), "");
+
+        assert_eq!(
+            vec!["a", "b", "a"]
+                .into_iter()
+                .collect::<BetaFeatures>()
+                .to_string(),
+            "a,b"
+        );
+
+        // A custom beta feature with a known value is deduplicated.
+        let beta_features = BetaFeatures::new()
+            .with(BetaFeature::Custom("pdfs-2024-09-25".to_string()))
+            .with(BetaFeature::Pdfs20240925);
+        assert_eq!(beta_features.to_string(), "pdfs-2024-09-25");
+        assert_eq!(
+            beta_features.iter().next(),
+            Some(&BetaFeature::Pdfs20240925)
+        );
+    }
+}
@@ -1,11 +1,26 @@
+use std::time::Duration;
+
 use futures_core::Stream;
-use reqwest::RequestBuilder;
+use reqwest::{Method, RequestBuilder, Response};
 
+use crate::batches::{
+    BatchResultsStreamResult, BatchesResult, CreateMessageBatchRequestBody,
+    MessageBatch,
+};
+use crate::completions::{
+    CompletionRequestBody, CompletionResponseBody, CompletionStreamResult,
+    CompletionsResult,
+};
 use crate::messages::{
-    ChunkStreamResult, MessagesRequestBody, MessagesResponseBody,
-    MessagesResult,
+    ChunkStreamResult, CountTokensResponseBody, MessagesRequestBody,
+    MessagesResponseBody, MessagesResult,
+};
+use crate::models::{ModelInfo, ModelsResult};
+use crate::rate_limit::RequestCost;
+use crate::{
+    ApiKey, ApiResponse, BaseUrl, BetaFeatures, ClientBuilder, ClientError,
+    Page, PaginationQuery, RateLimiter, RequestOptions, RetryPolicy, Version,
 };
-use crate::{ApiKey, Version};
 
 /// The API client.
 #[derive(Clone)]
@@ -14,6 +29,20 @@ pub struct Client {
     api_key: ApiKey,
     /// The API version.
     version: Version,
+    /// The base URL of the API.
+    base_url: BaseUrl,
+    /// The retry policy of the API calling.
+    retry_policy: RetryPolicy,
+    /// The beta features opted into for every request.
+    beta_features: BetaFeatures,
+    /// The client-side rate limiter of creating messages.
+    rate_limiter: Option<RateLimiter>,
+    /// The timeout to wait for the response headers of each attempt.
+    pub(crate) read_timeout: Option<Duration>,
+    /// The total timeout of a request.
+    pub(crate) timeout: Option<Duration>,
+    /// The total timeout of a streaming request.
+    pub(crate) stream_timeout: Option<Duration>,
     /// An HTTP client.
     client: reqwest::Client,
 }
@@ -44,12 +73,21 @@ impl Client {
         Self {
             api_key,
             version,
+            base_url: BaseUrl::default(),
+            retry_policy: RetryPolicy::disabled(),
+            beta_features: BetaFeatures::new(),
+            rate_limiter: None,
+            read_timeout: None,
+            timeout: None,
+            stream_timeout: None,
             client,
         }
     }
 
     /// Create a new API client with the API key loaded from the environment variable: `ANTHROPIC_API_KEY` and default options.
     ///
+    /// The base URL is overridden by the environment variable: `ANTHROPIC_BASE_URL` if it is set.
+    ///
     /// ## Example
     /// ```no_run
     /// use clust::Client;
@@ -60,8 +98,31 @@ impl Client {
         let api_key = ApiKey::from_env()?;
         let version = Version::default();
         let client = reqwest::Client::new();
+        let base_url = match BaseUrl::from_env() {
+            | Ok(base_url) => base_url,
+            | Err(std::env::VarError::NotPresent) => BaseUrl::default(),
+            | Err(error) => return Err(error),
+        };
+
+        Ok(Self::new(api_key, version, client).with_base_url(base_url))
+    }
 
-        Ok(Self::new(api_key, version, client))
+    /// Create a builder of the API client to configure the timeouts, the HTTP proxy, the default headers and so on.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use clust::Client;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let client = Client::builder()
+    ///     .with_timeout(Duration::from_secs(60))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
     }
 
     /// Create a new API client with the API key and default options.
@@ -84,18 +145,334 @@ impl Client {
         Self::new(api_key, version, client)
     }
 
+    /// Set the base URL of the API.
+    ///
+    /// Every endpoint is routed through this URL, e.g. `{base_url}/v1/messages`.
+    ///
+    /// ## Arguments
+    /// - `base_url` - The base URL.
+    ///
+    /// ## Example
+    /// ```
+    /// use clust::Client;
+    ///
+    /// let api_key = clust::ApiKey::new("api-key");
+    ///
+    /// let client = Client::from_api_key(api_key)
+    ///     .with_base_url("http://localhost:8080".into());
+    /// ```
+    pub fn with_base_url(
+        mut self,
+        base_url: BaseUrl,
+    ) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Set the retry policy of the API calling.
+    ///
+    /// Retries are disabled by default.
+    ///
+    /// ## Arguments
+    /// - `retry_policy` - The retry policy.
+    ///
+    /// ## Example
+    /// ```
+    /// use clust::{Client, RetryPolicy};
+    ///
+    /// let api_key = clust::ApiKey::new("api-key");
+    ///
+    /// let client = Client::from_api_key(api_key)
+    ///     .with_retry_policy(RetryPolicy::default());
+    /// ```
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the beta features opted into for every request by the `anthropic-beta` header.
+    ///
+    /// They are overridden by the beta features of each call, e.g. [`Client::create_a_message_with_beta_features`].
+    ///
+    /// ## Arguments
+    /// - `beta_features` - The beta features.
+    ///
+    /// ## Example
+    /// ```
+    /// use clust::{BetaFeature, BetaFeatures, Client};
+    ///
+    /// let api_key = clust::ApiKey::new("api-key");
+    ///
+    /// let client = Client::from_api_key(api_key)
+    ///     .with_beta_features(BetaFeatures::from(BetaFeature::Pdfs20240925));
+    /// ```
+    pub fn with_beta_features(
+        mut self,
+        beta_features: BetaFeatures,
+    ) -> Self {
+        self.beta_features = beta_features;
+        self
+    }
+
+    /// Set the total timeout of a request overriding the one of the builder.
+    ///
+    /// The client is cheap to clone, so the timeout of a single call can be overridden on a clone.
+    ///
+    /// ## Arguments
+    /// - `timeout` - The total timeout.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use clust::Client;
+    ///
+    /// let api_key = clust::ApiKey::new("api-key");
+    /// let client = Client::from_api_key(api_key);
+    ///
+    /// // Override the timeout only for the calls by this clone.
+    /// let quick_client = client
+    ///     .clone()
+    ///     .with_timeout(Duration::from_secs(5));
+    /// ```
+    pub fn with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the total timeout of a streaming request overriding the one of the builder.
+    ///
+    /// The client is cheap to clone, so the timeout of a single call can be overridden on a clone.
+    ///
+    /// ## Arguments
+    /// - `stream_timeout` - The total timeout of a streaming request.
+    pub fn with_stream_timeout(
+        mut self,
+        stream_timeout: Duration,
+    ) -> Self {
+        self.stream_timeout = Some(stream_timeout);
+        self
+    }
+
+    /// Set the client-side rate limiter, which makes the calls to create a message wait until the budgets allow them.
+    ///
+    /// The other endpoints are not rate-limited, see [`RateLimiter`].
+    ///
+    /// The clones of the client share the same rate limiter.
+    ///
+    /// ## Arguments
+    /// - `rate_limiter` - The rate limiter.
+    ///
+    /// ## Example
+    /// ```
+    /// use clust::{Client, RateLimiter};
+    ///
+    /// let api_key = clust::ApiKey::new("api-key");
+    ///
+    /// let client = Client::from_api_key(api_key)
+    ///     .with_rate_limiter(RateLimiter::new().with_requests_per_minute(50));
+    /// ```
+    pub fn with_rate_limiter(
+        mut self,
+        rate_limiter: RateLimiter,
+    ) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
     /// Create a request builder for the `POST` method.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint path relative to the base URL, e.g. `/v1/messages`.
     pub(crate) fn post(
         &self,
         endpoint: &str,
     ) -> RequestBuilder {
-        self.client
-            .post(endpoint)
+        self.request(
+            Method::POST,
+            endpoint,
+            self.timeout,
+            &self.beta_features,
+        )
+    }
+
+    /// Create a request builder for the `POST` method with the streaming response.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint path relative to the base URL, e.g. `/v1/messages`.
+    pub(crate) fn post_stream(
+        &self,
+        endpoint: &str,
+    ) -> RequestBuilder {
+        self.request(
+            Method::POST,
+            endpoint,
+            self.stream_timeout,
+            &self.beta_features,
+        )
+    }
+
+    /// Create a request builder for the `GET` method.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint path relative to the base URL, e.g. `/v1/messages/batches`.
+    pub(crate) fn get(
+        &self,
+        endpoint: &str,
+    ) -> RequestBuilder {
+        self.request(
+            Method::GET,
+            endpoint,
+            self.timeout,
+            &self.beta_features,
+        )
+    }
+
+    /// Create a request builder for the `GET` method with the streaming response.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint path relative to the base URL, e.g. `/v1/messages/batches/{id}/results`.
+    pub(crate) fn get_stream(
+        &self,
+        endpoint: &str,
+    ) -> RequestBuilder {
+        self.request(
+            Method::GET,
+            endpoint,
+            self.stream_timeout,
+            &self.beta_features,
+        )
+    }
+
+    /// Create a request builder with the authentication, version and beta headers.
+    ///
+    /// ## Arguments
+    /// - `method` - The HTTP method.
+    /// - `endpoint` - The endpoint path relative to the base URL.
+    /// - `timeout` - The total timeout of the request.
+    /// - `beta_features` - The beta features, which are not sent if empty.
+    pub(crate) fn request(
+        &self,
+        method: Method,
+        endpoint: &str,
+        timeout: Option<Duration>,
+        beta_features: &BetaFeatures,
+    ) -> RequestBuilder {
+        let mut request = self
+            .client
+            .request(method, self.base_url.join(endpoint))
             .header("x-api-key", self.api_key.value())
             .header(
                 "anthropic-version",
                 self.version.to_string(),
-            )
+            );
+
+        if !beta_features.is_empty() {
+            request = request.header(
+                "anthropic-beta",
+                beta_features.to_string(),
+            );
+        }
+
+        match timeout {
+            | Some(timeout) => request.timeout(timeout),
+            | None => request,
+        }
+    }
+
+    /// Returns the beta features of the call overriding the ones of the client.
+    pub(crate) fn beta_features<'a>(
+        &'a self,
+        beta_features: Option<&'a BetaFeatures>,
+    ) -> &'a BetaFeatures {
+        beta_features.unwrap_or(&self.beta_features)
+    }
+
+    /// Send the request with retrying by the retry policy.
+    ///
+    /// ## Arguments
+    /// - `request` - The request builder.
+    pub(crate) async fn send(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<Response, ClientError> {
+        self.send_with_cost(request, None)
+            .await
+    }
+
+    /// Send the request with retrying by the retry policy,
+    /// waiting for the rate limiter before each attempt and updating it by each response if the cost is given.
+    ///
+    /// ## Arguments
+    /// - `request` - The request builder.
+    /// - `cost` - The estimated cost of the request subject to the rate limiter.
+    pub(crate) async fn send_with_cost(
+        &self,
+        request: RequestBuilder,
+        cost: Option<&RequestCost>,
+    ) -> Result<Response, ClientError> {
+        let rate_limiter = self
+            .rate_limiter
+            .as_ref()
+            .zip(cost);
+        let mut request = request;
+        let mut attempt = 1;
+
+        loop {
+            if let Some((rate_limiter, cost)) = rate_limiter {
+                rate_limiter.acquire(cost).await;
+            }
+
+            // Keep a copy to retry, which is unavailable if the request body is a stream.
+            let retry_request = request.try_clone();
+            // The error is the read timeout elapsed before the response headers.
+            let result = match self.read_timeout {
+                | Some(read_timeout) => {
+                    tokio::time::timeout(read_timeout, request.send())
+                        .await
+                        .map_err(|_| read_timeout)
+                },
+                | None => Ok(request.send().await),
+            };
+
+            if let (Some((rate_limiter, _)), Ok(Ok(response))) =
+                (rate_limiter, &result)
+            {
+                rate_limiter.update(response.headers());
+            }
+
+            let delay = match &result {
+                | Ok(result) => self
+                    .retry_policy
+                    .retry_delay(attempt, result),
+                | Err(_) => self
+                    .retry_policy
+                    .read_timeout_retry_delay(attempt),
+            };
+
+            match (delay, retry_request) {
+                | (Some(delay), Some(retry_request)) => {
+                    tokio::time::sleep(delay).await;
+                    request = retry_request;
+                    attempt += 1;
+                },
+                | _ => {
+                    return match result {
+                        | Ok(result) => {
+                            result.map_err(ClientError::HttpRequestError)
+                        },
+                        | Err(read_timeout) => {
+                            Err(ClientError::ReadTimeout(read_timeout))
+                        },
+                    };
+                },
+            }
+        }
     }
 }
 
@@ -123,7 +500,7 @@ impl Client {
     /// async fn main() -> anyhow::Result<()> {
     ///     let client = Client::from_env()?;
     ///     let model = ClaudeModel::Claude3Sonnet20240229;
-    ///     let max_tokens = MaxTokens::new(1024, model)?;
+    ///     let max_tokens = MaxTokens::new(1024, &model)?;
     ///     let request_body = MessagesRequestBody {
     ///         model,
     ///         max_tokens,
@@ -144,7 +521,162 @@ impl Client {
         &self,
         request_body: MessagesRequestBody,
     ) -> MessagesResult<MessagesResponseBody> {
-        crate::messages::api::create_a_message(self, request_body).await
+        crate::messages::api::create_a_message(
+            self,
+            request_body,
+            &RequestOptions::new(),
+        )
+        .await
+    }
+
+    /// Create a Message with the beta features overriding the ones of the client.
+    ///
+    /// See also [`Client::create_a_message`].
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    /// - `beta_features` - The beta features of this call.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::{BetaFeature, BetaFeatures, Client};
+    /// use clust::messages::{MessagesRequestBody, ClaudeModel, Message, MaxTokens};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude3Sonnet20240229;
+    ///     let max_tokens = MaxTokens::new(1024, &model)?;
+    ///     let request_body = MessagesRequestBody {
+    ///         model,
+    ///         max_tokens,
+    ///         messages: vec![
+    ///             Message::user("Hello, Claude!"),
+    ///         ],
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let response = client
+    ///         .create_a_message_with_beta_features(
+    ///             request_body,
+    ///             &BetaFeatures::from(BetaFeature::Pdfs20240925),
+    ///         )
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_a_message_with_beta_features(
+        &self,
+        request_body: MessagesRequestBody,
+        beta_features: &BetaFeatures,
+    ) -> MessagesResult<MessagesResponseBody> {
+        crate::messages::api::create_a_message(
+            self,
+            request_body,
+            &RequestOptions::new()
+                .with_beta_features(beta_features.clone()),
+        )
+        .await
+    }
+
+    /// Create a Message with the request options, e.g. extra headers, extra body fields, timeout and cancellation.
+    ///
+    /// See also [`Client::create_a_message`].
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    /// - `options` - The request options of this call.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use clust::{CancellationToken, Client, RequestOptions};
+    /// use clust::messages::{MessagesRequestBody, ClaudeModel, Message, MaxTokens};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude3Sonnet20240229;
+    ///     let max_tokens = MaxTokens::new(1024, &model)?;
+    ///     let request_body = MessagesRequestBody {
+    ///         model,
+    ///         max_tokens,
+    ///         messages: vec![
+    ///             Message::user("Hello, Claude!"),
+    ///         ],
+    ///         ..Default::default()
+    ///     };
+    ///     let cancellation_token = CancellationToken::new();
+    ///     let options = RequestOptions::new()
+    ///         .with_header("x-trace-id", "trace")
+    ///         .with_extra_body_field("metadata", serde_json::json!({"user_id": "user"}))
+    ///         .with_timeout(Duration::from_secs(30))
+    ///         .with_cancellation_token(cancellation_token.clone());
+    ///
+    ///     let response = client
+    ///         .create_a_message_with_options(request_body, options)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_a_message_with_options(
+        &self,
+        request_body: MessagesRequestBody,
+        options: RequestOptions,
+    ) -> MessagesResult<MessagesResponseBody> {
+        crate::messages::api::create_a_message(self, request_body, &options)
+            .await
+    }
+
+    /// Create a Message with the metadata of the HTTP response, i.e. the status code, the request ID and the rate limits.
+    ///
+    /// See also [`Client::create_a_message`].
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::messages::{MessagesRequestBody, ClaudeModel, Message, MaxTokens};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude3Sonnet20240229;
+    ///     let max_tokens = MaxTokens::new(1024, &model)?;
+    ///     let request_body = MessagesRequestBody {
+    ///         model,
+    ///         max_tokens,
+    ///         messages: vec![
+    ///             Message::user("Hello, Claude!"),
+    ///         ],
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let response = client
+    ///         .create_a_message_with_response(request_body)
+    ///         .await?;
+    ///
+    ///     let request_id = response.metadata.request_id;
+    ///     let remaining_tokens = response.metadata.rate_limits.tokens.remaining;
+    ///     let message = response.body;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_a_message_with_response(
+        &self,
+        request_body: MessagesRequestBody,
+    ) -> MessagesResult<ApiResponse<MessagesResponseBody>> {
+        crate::messages::api::create_a_message_with_response(
+            self,
+            request_body,
+            &RequestOptions::new(),
+        )
+        .await
     }
 
     /// Create a Message with incrementally streaming the response using server-sent events (SSE).
@@ -157,6 +689,8 @@ impl Client {
     /// ## NOTE
     /// The `stream` option must be `StreamOption::ReturnStream`.
     ///
+    /// The retry policy applies only to establishing the stream, errors in the middle of the stream are not retried.
+    ///
     /// ## Example
     /// ```no_run
     /// use clust::Client;
@@ -167,7 +701,7 @@ impl Client {
     /// async fn main() -> anyhow::Result<()> {
     ///     let client = Client::from_env()?;
     ///     let model = ClaudeModel::Claude3Sonnet20240229;
-    ///     let max_tokens = MaxTokens::new(1024, model)?;
+    ///     let max_tokens = MaxTokens::new(1024, &model)?;
     ///     let request_body = MessagesRequestBody {
     ///         model,
     ///         max_tokens,
@@ -200,6 +734,601 @@ impl Client {
         &self,
         request_body: MessagesRequestBody,
     ) -> MessagesResult<impl Stream<Item = ChunkStreamResult>> {
-        crate::messages::api::create_a_message_stream(self, request_body).await
+        crate::messages::api::create_a_message_stream(
+            self,
+            request_body,
+            &RequestOptions::new(),
+        )
+        .await
+    }
+
+    /// Create a Message with incrementally streaming the response with the beta features overriding the ones of the client.
+    ///
+    /// See also [`Client::create_a_message_stream`].
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    /// - `beta_features` - The beta features of this call.
+    pub async fn create_a_message_stream_with_beta_features(
+        &self,
+        request_body: MessagesRequestBody,
+        beta_features: &BetaFeatures,
+    ) -> MessagesResult<impl Stream<Item = ChunkStreamResult>> {
+        crate::messages::api::create_a_message_stream(
+            self,
+            request_body,
+            &RequestOptions::new()
+                .with_beta_features(beta_features.clone()),
+        )
+        .await
+    }
+
+    /// Create a Message with incrementally streaming the response with the request options, e.g. extra headers, extra body fields, timeout and cancellation.
+    ///
+    /// The stream ends with [`StreamError::Cancelled`](crate::messages::StreamError::Cancelled) when the request is cancelled in the middle of the stream.
+    ///
+    /// See also [`Client::create_a_message_stream`].
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    /// - `options` - The request options of this call.
+    pub async fn create_a_message_stream_with_options(
+        &self,
+        request_body: MessagesRequestBody,
+        options: RequestOptions,
+    ) -> MessagesResult<impl Stream<Item = ChunkStreamResult>> {
+        crate::messages::api::create_a_message_stream(
+            self,
+            request_body,
+            &options,
+        )
+        .await
+    }
+
+    /// Count the number of tokens in a Message.
+    ///
+    /// The token count is for the input of the request body, including messages, the system prompt and tools.
+    /// The generation parameters, e.g. `max_tokens` and `stream`, are not sent.
+    ///
+    /// See also [Count Message tokens](https://docs.anthropic.com/en/api/messages-count-tokens).
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::messages::{MessagesRequestBody, ClaudeModel, Message, MaxTokens};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude3Sonnet20240229;
+    ///     let max_tokens = MaxTokens::new(1024, &model)?;
+    ///     let request_body = MessagesRequestBody {
+    ///         model,
+    ///         max_tokens,
+    ///         messages: vec![
+    ///             Message::user("Hello, Claude!"),
+    ///         ],
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let response = client
+    ///         .count_tokens(request_body)
+    ///         .await?;
+    ///
+    ///     println!("Input tokens: {}", response.input_tokens);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn count_tokens(
+        &self,
+        request_body: MessagesRequestBody,
+    ) -> MessagesResult<CountTokensResponseBody> {
+        crate::messages::api::count_tokens(
+            self,
+            request_body,
+            &RequestOptions::new(),
+        )
+        .await
+    }
+
+    /// Count the number of tokens in a Message with the beta features overriding the ones of the client.
+    ///
+    /// See also [`Client::count_tokens`].
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    /// - `beta_features` - The beta features of this call.
+    pub async fn count_tokens_with_beta_features(
+        &self,
+        request_body: MessagesRequestBody,
+        beta_features: &BetaFeatures,
+    ) -> MessagesResult<CountTokensResponseBody> {
+        crate::messages::api::count_tokens(
+            self,
+            request_body,
+            &RequestOptions::new()
+                .with_beta_features(beta_features.clone()),
+        )
+        .await
+    }
+}
+
+impl Client {
+    /// Create a Message Batch.
+    ///
+    /// Send a batch of Message creation requests, which are processed asynchronously.
+    ///
+    /// See also [Create a Message Batch](https://docs.anthropic.com/en/api/creating-message-batches).
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::batches::{CreateMessageBatchRequestBody, MessageBatchRequest};
+    /// use clust::messages::{MessagesRequestBody, ClaudeModel, Message, MaxTokens};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude3Haiku20240307;
+    ///     let max_tokens = MaxTokens::new(1024, &model)?;
+    ///     let request_body = CreateMessageBatchRequestBody::new(vec![
+    ///         MessageBatchRequest::new(
+    ///             "request-1",
+    ///             MessagesRequestBody {
+    ///                 model,
+    ///                 max_tokens,
+    ///                 messages: vec![
+    ///                     Message::user("Hello, Claude!"),
+    ///                 ],
+    ///                 ..Default::default()
+    ///             },
+    ///         ),
+    ///     ]);
+    ///
+    ///     let message_batch = client
+    ///         .create_a_message_batch(request_body)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_a_message_batch(
+        &self,
+        request_body: CreateMessageBatchRequestBody,
+    ) -> BatchesResult<MessageBatch> {
+        crate::batches::api::create_a_message_batch(self, request_body).await
+    }
+
+    /// Retrieve a Message Batch.
+    ///
+    /// This endpoint is idempotent and can be used to poll for the completion of a message batch.
+    ///
+    /// See also [Retrieve a Message Batch](https://docs.anthropic.com/en/api/retrieving-message-batches).
+    ///
+    /// ## Arguments
+    /// - `message_batch_id` - The ID of the message batch.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::batches::ProcessingStatus;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let message_batch = client
+    ///         .retrieve_a_message_batch("msgbatch_01")
+    ///         .await?;
+    ///
+    ///     if message_batch.processing_status == ProcessingStatus::Ended {
+    ///         // Retrieve the results.
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn retrieve_a_message_batch(
+        &self,
+        message_batch_id: &str,
+    ) -> BatchesResult<MessageBatch> {
+        crate::batches::api::retrieve_a_message_batch(self, message_batch_id)
+            .await
+    }
+
+    /// List all Message Batches within a workspace, most recently created first.
+    ///
+    /// See also [List Message Batches](https://docs.anthropic.com/en/api/listing-message-batches).
+    ///
+    /// ## Arguments
+    /// - `query` - The pagination query.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::{Client, PaginationQuery};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let mut query = PaginationQuery::default();
+    ///     loop {
+    ///         let page = client
+    ///             .list_message_batches(query)
+    ///             .await?;
+    ///
+    ///         for message_batch in &page.data {
+    ///             println!("{}", message_batch.id);
+    ///         }
+    ///
+    ///         match page.next_page_query(None) {
+    ///             | Some(next) => query = next,
+    ///             | None => break,
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list_message_batches(
+        &self,
+        query: PaginationQuery,
+    ) -> BatchesResult<Page<MessageBatch>> {
+        crate::batches::api::list_message_batches(self, query).await
+    }
+
+    /// Cancel a Message Batch.
+    ///
+    /// Batches may be canceled any time before processing ends.
+    /// The batch is in the `canceling` state until the cancellation is completed.
+    ///
+    /// See also [Cancel a Message Batch](https://docs.anthropic.com/en/api/canceling-message-batches).
+    ///
+    /// ## Arguments
+    /// - `message_batch_id` - The ID of the message batch.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let message_batch = client
+    ///         .cancel_a_message_batch("msgbatch_01")
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn cancel_a_message_batch(
+        &self,
+        message_batch_id: &str,
+    ) -> BatchesResult<MessageBatch> {
+        crate::batches::api::cancel_a_message_batch(self, message_batch_id)
+            .await
+    }
+
+    /// Retrieve the results of a Message Batch as a stream.
+    ///
+    /// The results are streamed line by line from the `.jsonl` file and are not guaranteed to be in the same order as the requests.
+    /// Use the `custom_id` field to match results to requests.
+    ///
+    /// See also [Retrieve Message Batch Results](https://docs.anthropic.com/en/api/retrieving-message-batch-results).
+    ///
+    /// ## Arguments
+    /// - `message_batch_id` - The ID of the message batch.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use tokio_stream::StreamExt; // or futures_util::StreamExt to `stream.next().await`.
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let mut stream = client
+    ///         .retrieve_message_batch_results("msgbatch_01")
+    ///         .await?;
+    ///
+    ///     while let Some(result) = stream.next().await {
+    ///         let result = result?;
+    ///         println!("{}: {}", result.custom_id, result.result);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn retrieve_message_batch_results(
+        &self,
+        message_batch_id: &str,
+    ) -> BatchesResult<impl Stream<Item = BatchResultsStreamResult>> {
+        crate::batches::api::retrieve_message_batch_results(
+            self,
+            message_batch_id,
+        )
+        .await
+    }
+}
+
+impl Client {
+    /// List available models, the most recently released models first.
+    ///
+    /// See also [List Models](https://docs.anthropic.com/en/api/models-list).
+    ///
+    /// ## Arguments
+    /// - `query` - The pagination query.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::{Client, PaginationQuery};
+    /// use clust::messages::ClaudeModel;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let page = client
+    ///         .list_models(PaginationQuery::default())
+    ///         .await?;
+    ///
+    ///     let models: Vec<ClaudeModel> = page
+    ///         .data
+    ///         .into_iter()
+    ///         .map(ClaudeModel::from)
+    ///         .collect();
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list_models(
+        &self,
+        query: PaginationQuery,
+    ) -> ModelsResult<Page<ModelInfo>> {
+        crate::models::api::list_models(self, query).await
+    }
+
+    /// Get a specific model.
+    ///
+    /// See also [Get a Model](https://docs.anthropic.com/en/api/models).
+    ///
+    /// ## Arguments
+    /// - `model_id` - The model identifier or alias.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///
+    ///     let model_info = client
+    ///         .get_model("claude-3-haiku-20240307")
+    ///         .await?;
+    ///
+    ///     println!("{}", model_info.display_name);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_model(
+        &self,
+        model_id: &str,
+    ) -> ModelsResult<ModelInfo> {
+        crate::models::api::get_model(self, model_id).await
+    }
+}
+
+impl Client {
+    /// Create a Text Completion with the legacy Text Completions API.
+    ///
+    /// The Messages API is recommended for new applications, see [`Client::create_a_message`].
+    ///
+    /// See also [Create a Text Completion](https://docs.anthropic.com/claude/reference/complete_post).
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    ///
+    /// ## NOTE
+    /// The `stream` option must be `None` or `StreamOption::ReturnOnce`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::completions::{CompletionRequestBody, Prompt};
+    /// use clust::messages::{ClaudeModel, MaxTokens, Message};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude21;
+    ///     let max_tokens_to_sample = MaxTokens::new(1024, &model)?;
+    ///     let prompt = Prompt::from_messages(
+    ///         None,
+    ///         &[Message::user("Hello, Claude!")],
+    ///     )?;
+    ///     let request_body = CompletionRequestBody {
+    ///         model,
+    ///         prompt,
+    ///         max_tokens_to_sample,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let response = client
+    ///         .create_a_completion(request_body)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_a_completion(
+        &self,
+        request_body: CompletionRequestBody,
+    ) -> CompletionsResult<CompletionResponseBody> {
+        crate::completions::api::create_a_completion(self, request_body).await
+    }
+
+    /// Create a Text Completion with incrementally streaming the response using server-sent events (SSE).
+    ///
+    /// See also [Streaming Text Completions](https://docs.anthropic.com/claude/reference/streaming).
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    ///
+    /// ## NOTE
+    /// The `stream` option must be `StreamOption::ReturnStream`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::completions::{CompletionChunk, CompletionRequestBody, Prompt};
+    /// use clust::messages::{ClaudeModel, MaxTokens, StreamOption};
+    /// use tokio_stream::StreamExt; // or futures_util::StreamExt to `stream.next().await`.
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude21;
+    ///     let max_tokens_to_sample = MaxTokens::new(1024, &model)?;
+    ///     let request_body = CompletionRequestBody {
+    ///         model,
+    ///         prompt: Prompt::new("\n\nHuman: Hello, Claude!\n\nAssistant:"),
+    ///         max_tokens_to_sample,
+    ///         stream: Some(StreamOption::ReturnStream),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let mut stream = client
+    ///         .create_a_completion_stream(request_body)
+    ///         .await?;
+    ///
+    ///     while let Some(chunk) = stream.next().await {
+    ///         if let CompletionChunk::Completion(completion) = chunk? {
+    ///             print!("{}", completion.completion);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_a_completion_stream(
+        &self,
+        request_body: CompletionRequestBody,
+    ) -> CompletionsResult<impl Stream<Item = CompletionStreamResult>> {
+        crate::completions::api::create_a_completion_stream(self, request_body)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BetaFeature;
+
+    #[test]
+    fn request_headers() {
+        let client = Client::from_api_key(ApiKey::new("api-key"));
+        let request = client
+            .post("/v1/messages")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.anthropic.com/v1/messages"
+        );
+        assert_eq!(
+            request.headers()["x-api-key"],
+            "api-key"
+        );
+        assert_eq!(
+            request.headers()["anthropic-version"],
+            "2023-06-01"
+        );
+        assert!(request
+            .headers()
+            .get("anthropic-beta")
+            .is_none());
+    }
+
+    #[test]
+    fn request_beta_headers() {
+        let client = Client::from_api_key(ApiKey::new("api-key"))
+            .with_beta_features(
+                BetaFeatures::new()
+                    .with(BetaFeature::PromptCaching20240731)
+                    .with(BetaFeature::Pdfs20240925),
+            );
+        let request = client
+            .post("/v1/messages")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.headers()["anthropic-beta"],
+            "prompt-caching-2024-07-31,pdfs-2024-09-25"
+        );
+
+        // Overridden by the beta features of the call.
+        let beta_features = BetaFeatures::from(BetaFeature::new("custom"));
+        let request = client
+            .request(
+                Method::POST,
+                "/v1/messages",
+                None,
+                client.beta_features(Some(&beta_features)),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            request
+                .headers()
+                .get_all("anthropic-beta")
+                .iter()
+                .collect::<Vec<_>>(),
+            vec!["custom"]
+        );
+    }
+
+    #[test]
+    fn request_timeout() {
+        let client = Client::from_api_key(ApiKey::new("api-key"));
+        let overridden = client
+            .clone()
+            .with_timeout(Duration::from_secs(5))
+            .with_stream_timeout(Duration::from_secs(60));
+
+        assert_eq!(
+            client
+                .post("/v1/messages")
+                .build()
+                .unwrap()
+                .timeout(),
+            None
+        );
+        assert_eq!(
+            overridden
+                .post("/v1/messages")
+                .build()
+                .unwrap()
+                .timeout(),
+            Some(&Duration::from_secs(5))
+        );
+        assert_eq!(
+            overridden
+                .post_stream("/v1/messages")
+                .build()
+                .unwrap()
+                .timeout(),
+            Some(&Duration::from_secs(60))
+        );
     }
 }
@@ -0,0 +1,532 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::{
+    ApiKey, BaseUrl, BetaFeatures, Client, ClientBuilderError, RateLimiter,
+    RetryPolicy, ValidationError, Version,
+};
+
+/// The user agent identifying this crate, i.e. `clust/<version>`.
+pub(crate) const USER_AGENT: &str =
+    concat!("clust/", env!("CARGO_PKG_VERSION"));
+
+/// The headers set by the client, which can not be overridden by the default headers or the request options.
+///
+/// The `anthropic-beta` header is set by the beta features, which would otherwise be sent twice.
+pub(crate) const RESERVED_HEADERS: [&str; 3] =
+    ["x-api-key", "anthropic-version", "anthropic-beta"];
+
+/// The builder of the API client.
+///
+/// It configures the API key, the API version, the base URL, the timeouts, the HTTP proxy,
+/// the default headers and the user agent, then validates the combination at [`ClientBuilder::build`].
+///
+/// ## Example
+/// ```no_run
+/// use std::time::Duration;
+/// use clust::{ApiKey, ClientBuilder};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let client = ClientBuilder::new()
+///     .with_api_key(ApiKey::new("api-key"))
+///     .with_connect_timeout(Duration::from_secs(5))
+///     .with_read_timeout(Duration::from_secs(30))
+///     .with_timeout(Duration::from_secs(120))
+///     .with_proxy("http://localhost:3128")
+///     .with_default_header("x-trace-id", "trace")
+///     .with_user_agent("my-app/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    /// The API key, loaded from the environment variable if not set.
+    api_key: Option<ApiKey>,
+    /// The API version.
+    version: Version,
+    /// The base URL, loaded from the environment variable or the default if not set.
+    base_url: Option<BaseUrl>,
+    /// The retry policy.
+    retry_policy: Option<RetryPolicy>,
+    /// The beta features opted into for every request.
+    beta_features: BetaFeatures,
+    /// The client-side rate limiter.
+    rate_limiter: Option<RateLimiter>,
+    /// The timeout to connect to the server.
+    connect_timeout: Option<Duration>,
+    /// The timeout to wait for the response headers of each attempt.
+    read_timeout: Option<Duration>,
+    /// The total timeout of a request.
+    timeout: Option<Duration>,
+    /// The total timeout of a streaming request.
+    stream_timeout: Option<Duration>,
+    /// The URL of the HTTP proxy.
+    proxy: Option<String>,
+    /// The extra default headers.
+    default_headers: Vec<(String, String)>,
+    /// The user agent of the application.
+    user_agent: Option<String>,
+    /// The prebuilt HTTP client.
+    http_client: Option<reqwest::Client>,
+}
+
+impl ClientBuilder {
+    /// Creates a new client builder with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the API key.
+    ///
+    /// It is loaded from the environment variable: `ANTHROPIC_API_KEY` if not set.
+    ///
+    /// ## Arguments
+    /// - `api_key` - The API key.
+    pub fn with_api_key(
+        mut self,
+        api_key: ApiKey,
+    ) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Sets the API version.
+    ///
+    /// ## Arguments
+    /// - `version` - The API version.
+    pub fn with_version(
+        mut self,
+        version: Version,
+    ) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the base URL of the API.
+    ///
+    /// It is loaded from the environment variable: `ANTHROPIC_BASE_URL` or the official API if not set.
+    ///
+    /// ## Arguments
+    /// - `base_url` - The base URL.
+    pub fn with_base_url(
+        mut self,
+        base_url: BaseUrl,
+    ) -> Self {
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// Sets the retry policy of the API calling.
+    ///
+    /// ## Arguments
+    /// - `retry_policy` - The retry policy.
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets the beta features opted into for every request by the `anthropic-beta` header.
+    ///
+    /// ## Arguments
+    /// - `beta_features` - The beta features.
+    pub fn with_beta_features(
+        mut self,
+        beta_features: BetaFeatures,
+    ) -> Self {
+        self.beta_features = beta_features;
+        self
+    }
+
+    /// Sets the client-side rate limiter of creating messages.
+    ///
+    /// ## Arguments
+    /// - `rate_limiter` - The rate limiter.
+    pub fn with_rate_limiter(
+        mut self,
+        rate_limiter: RateLimiter,
+    ) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets the timeout to connect to the server.
+    ///
+    /// ## Arguments
+    /// - `timeout` - The connect timeout.
+    pub fn with_connect_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout to wait for the response headers after sending the request.
+    ///
+    /// It is applied to each attempt and retried as a timeout error of [`crate::HttpErrorKind::Timeout`] by the retry policy.
+    ///
+    /// ## Arguments
+    /// - `timeout` - The read timeout.
+    pub fn with_read_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the total timeout of a request from connecting until the response body has finished.
+    ///
+    /// It is not applied to a streaming request, see [`ClientBuilder::with_stream_timeout`].
+    ///
+    /// ## Arguments
+    /// - `timeout` - The total timeout.
+    pub fn with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the total timeout of a streaming request, which overrides the total timeout for streams.
+    ///
+    /// A streaming request has no total timeout by default because generating a long response takes minutes.
+    ///
+    /// ## Arguments
+    /// - `timeout` - The total timeout of a streaming request.
+    pub fn with_stream_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.stream_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the HTTP proxy for all requests.
+    ///
+    /// ## Arguments
+    /// - `url` - The URL of the proxy, e.g. `http://localhost:3128`.
+    pub fn with_proxy<S>(
+        mut self,
+        url: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Adds an extra default header sent with every request.
+    ///
+    /// The headers of the authentication, the API version and the beta features can not be overridden,
+    /// use [`ClientBuilder::with_beta_features`] for the beta features.
+    ///
+    /// ## Arguments
+    /// - `name` - The header name.
+    /// - `value` - The header value.
+    pub fn with_default_header<N, V>(
+        mut self,
+        name: N,
+        value: V,
+    ) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.default_headers
+            .push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the user agent of the application, which is prepended to the user agent of this crate,
+    /// e.g. `my-app/1.0 clust/<version>`.
+    ///
+    /// ## Arguments
+    /// - `user_agent` - The user agent of the application.
+    pub fn with_user_agent<S>(
+        mut self,
+        user_agent: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the prebuilt HTTP client instead of building it from the options.
+    ///
+    /// It conflicts with the connect timeout, the proxy, the default headers and the user agent,
+    /// which configure the HTTP client.
+    ///
+    /// ## Arguments
+    /// - `http_client` - The HTTP client.
+    pub fn with_http_client(
+        mut self,
+        http_client: reqwest::Client,
+    ) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Builds the API client.
+    ///
+    /// ## Errors
+    /// It returns an error if the API key is missing or the combination of the options is invalid.
+    pub fn build(self) -> Result<Client, ClientBuilderError> {
+        let api_key = match self.api_key {
+            | Some(api_key) => api_key,
+            | None => ApiKey::from_env()
+                .map_err(|_| ClientBuilderError::MissingApiKey)?,
+        };
+
+        let base_url = match self.base_url {
+            | Some(base_url) => base_url,
+            | None => match BaseUrl::from_env() {
+                | Ok(base_url) => base_url,
+                | Err(std::env::VarError::NotPresent) => BaseUrl::default(),
+                | Err(error) => {
+                    return Err(ClientBuilderError::InvalidEnvVar(error))
+                },
+            },
+        };
+
+        validate_timeouts(
+            self.connect_timeout,
+            self.read_timeout,
+            self.timeout,
+            self.stream_timeout,
+        )?;
+
+        let client = match self.http_client {
+            | Some(http_client) => {
+                if self.connect_timeout.is_some()
+                    || self.proxy.is_some()
+                    || !self.default_headers.is_empty()
+                    || self.user_agent.is_some()
+                {
+                    return Err(ClientBuilderError::ConflictingHttpClient);
+                }
+                http_client
+            },
+            | None => {
+                let mut builder = reqwest::Client::builder()
+                    .default_headers(build_default_headers(
+                        &self.default_headers,
+                    )?)
+                    .user_agent(match self.user_agent {
+                        | Some(user_agent) => {
+                            format!("{} {}", user_agent, USER_AGENT)
+                        },
+                        | None => USER_AGENT.to_string(),
+                    });
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(
+                        reqwest::Proxy::all(proxy)
+                            .map_err(ClientBuilderError::InvalidProxy)?,
+                    );
+                }
+                builder
+                    .build()
+                    .map_err(ClientBuilderError::HttpClientBuildFailed)?
+            },
+        };
+
+        let mut client = Client::new(api_key, self.version, client)
+            .with_base_url(base_url)
+            .with_beta_features(self.beta_features);
+        if let Some(retry_policy) = self.retry_policy {
+            client = client.with_retry_policy(retry_policy);
+        }
+        if let Some(rate_limiter) = self.rate_limiter {
+            client = client.with_rate_limiter(rate_limiter);
+        }
+        client.read_timeout = self.read_timeout;
+        client.timeout = self.timeout;
+        client.stream_timeout = self.stream_timeout;
+
+        Ok(client)
+    }
+}
+
+/// Validates the combination of the timeouts.
+fn validate_timeouts(
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    stream_timeout: Option<Duration>,
+) -> Result<(), ValidationError<String>> {
+    for (name, value) in [
+        ("connect_timeout", connect_timeout),
+        ("read_timeout", read_timeout),
+        ("timeout", timeout),
+        ("stream_timeout", stream_timeout),
+    ] {
+        if value == Some(Duration::ZERO) {
+            return Err(ValidationError {
+                _type: "ClientBuilder".to_string(),
+                expected: format!("The {} must be greater than zero.", name),
+                actual: format!("{}: 0s", name),
+            });
+        }
+    }
+
+    // A shorter total timeout makes the connect and read timeouts meaningless.
+    if let Some(timeout) = timeout {
+        for (name, value) in [
+            ("connect_timeout", connect_timeout),
+            ("read_timeout", read_timeout),
+        ] {
+            if let Some(value) = value {
+                if value > timeout {
+                    return Err(ValidationError {
+                        _type: "ClientBuilder".to_string(),
+                        expected: format!(
+                            "The {} must not exceed the total timeout: {:?}.",
+                            name, timeout
+                        ),
+                        actual: format!("{}: {:?}", name, value),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the default headers validating the names and values.
+fn build_default_headers(
+    headers: &[(String, String)]
+) -> Result<HeaderMap, ClientBuilderError> {
+    let mut header_map = HeaderMap::new();
+    for (name, value) in headers {
+        let header_name =
+            HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+                ClientBuilderError::InvalidHeader(name.clone())
+            })?;
+        if RESERVED_HEADERS.contains(&header_name.as_str()) {
+            return Err(ClientBuilderError::ReservedHeader(name.clone()));
+        }
+        let header_value = HeaderValue::from_str(value).map_err(|_| {
+            ClientBuilderError::InvalidHeader(name.clone())
+        })?;
+        header_map.append(header_name, header_value);
+    }
+
+    Ok(header_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> ClientBuilder {
+        ClientBuilder::new().with_api_key(ApiKey::new("api-key"))
+    }
+
+    #[test]
+    fn build() {
+        let client = builder()
+            .with_version(Version::V2023_01_01)
+            .with_base_url("http://localhost:8080".into())
+            .with_connect_timeout(Duration::from_secs(5))
+            .with_read_timeout(Duration::from_secs(30))
+            .with_timeout(Duration::from_secs(60))
+            .with_stream_timeout(Duration::from_secs(600))
+            .with_proxy("http://localhost:3128")
+            .with_default_header("x-trace-id", "trace")
+            .with_user_agent("my-app/1.0")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.read_timeout,
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(client.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(
+            client.stream_timeout,
+            Some(Duration::from_secs(600))
+        );
+
+        assert!(builder()
+            .with_http_client(reqwest::Client::new())
+            .with_timeout(Duration::from_secs(60))
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn build_invalid_timeouts() {
+        assert!(matches!(
+            builder()
+                .with_timeout(Duration::ZERO)
+                .build(),
+            Err(ClientBuilderError::ValidationError(_))
+        ));
+        assert!(matches!(
+            builder()
+                .with_connect_timeout(Duration::from_secs(10))
+                .with_timeout(Duration::from_secs(5))
+                .build(),
+            Err(ClientBuilderError::ValidationError(_))
+        ));
+        assert!(matches!(
+            builder()
+                .with_read_timeout(Duration::from_secs(10))
+                .with_timeout(Duration::from_secs(5))
+                .build(),
+            Err(ClientBuilderError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn build_invalid_http_options() {
+        assert!(matches!(
+            builder()
+                .with_http_client(reqwest::Client::new())
+                .with_proxy("http://localhost:3128")
+                .build(),
+            Err(ClientBuilderError::ConflictingHttpClient)
+        ));
+        assert!(matches!(
+            builder()
+                .with_default_header("invalid header", "value")
+                .build(),
+            Err(ClientBuilderError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            builder()
+                .with_default_header("X-API-Key", "other")
+                .build(),
+            Err(ClientBuilderError::ReservedHeader(_))
+        ));
+        assert!(matches!(
+            builder()
+                .with_default_header("anthropic-beta", "pdfs-2024-09-25")
+                .build(),
+            Err(ClientBuilderError::ReservedHeader(_))
+        ));
+        assert!(matches!(
+            builder()
+                .with_proxy("not a url")
+                .build(),
+            Err(ClientBuilderError::InvalidProxy(_))
+        ));
+    }
+
+    #[test]
+    fn user_agent() {
+        assert!(USER_AGENT.starts_with("clust/"));
+    }
+}
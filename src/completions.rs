@@ -0,0 +1,23 @@
+//! The legacy [Text Completions API](https://docs.anthropic.com/claude/reference/complete_post) implementations.
+//!
+//! The Messages API is recommended for new applications, see [`crate::messages`].
+
+mod completion_chunk;
+mod completion_request_body;
+mod completion_response_body;
+mod error;
+mod prompt;
+mod result;
+
+pub(crate) mod api;
+
+pub use completion_chunk::CompletionChunk;
+pub use completion_request_body::CompletionRequestBody;
+pub use completion_response_body::CompletionObjectType;
+pub use completion_response_body::CompletionResponseBody;
+pub use error::CompletionsError;
+pub use prompt::Prompt;
+pub use prompt::AI_PROMPT;
+pub use prompt::HUMAN_PROMPT;
+pub use result::CompletionStreamResult;
+pub use result::CompletionsResult;
@@ -0,0 +1,67 @@
+use crate::completions::{
+    CompletionChunk, CompletionRequestBody, CompletionResponseBody,
+    CompletionStreamResult, CompletionsError, CompletionsResult,
+};
+use crate::messages::chunk_stream::ChunkStream;
+use crate::messages::StreamOption;
+use crate::response::{read_error_response, read_response};
+use crate::Client;
+use futures_core::Stream;
+
+pub(crate) async fn create_a_completion(
+    client: &Client,
+    request_body: CompletionRequestBody,
+) -> CompletionsResult<CompletionResponseBody> {
+    // Validate stream option.
+    if let Some(stream) = &request_body.stream {
+        if *stream != StreamOption::ReturnOnce {
+            return Err(CompletionsError::StreamOptionMismatch);
+        }
+    }
+
+    // Send the request.
+    let response = client
+        .send(
+            client
+                .post("/v1/complete")
+                .json(&request_body),
+        )
+        .await?;
+
+    read_response(response).await
+}
+
+pub(crate) async fn create_a_completion_stream(
+    client: &Client,
+    request_body: CompletionRequestBody,
+) -> CompletionsResult<impl Stream<Item = CompletionStreamResult>> {
+    // Validate stream option.
+    if request_body.stream != Some(StreamOption::ReturnStream) {
+        return Err(CompletionsError::StreamOptionMismatch);
+    }
+
+    // Send the request.
+    let response = client
+        .send(
+            client
+                .post_stream("/v1/complete")
+                .json(&request_body),
+        )
+        .await?;
+
+    // Check the response status code.
+    let status_code = response.status();
+
+    // Ok
+    if status_code.is_success() {
+        // Create a chunk stream from response bytes stream.
+        let byte_stream = response.bytes_stream();
+        let chunk_stream =
+            ChunkStream::<_, CompletionChunk>::new(byte_stream);
+        Ok(chunk_stream)
+    }
+    // Error
+    else {
+        Err(read_error_response(response).await)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::completions::CompletionResponseBody;
+use crate::messages::chunk_stream::ParseChunk;
+use crate::messages::{PingChunk, StreamError, UnknownChunk};
+use crate::sse::SseEvent;
+use crate::ApiErrorResponse;
+use std::fmt::Display;
+
+/// The stream chunk of the legacy Text Completions API.
+///
+/// An `error` event is surfaced as [`StreamError::ApiError`] instead of a chunk.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompletionChunk {
+    /// The completion chunk with a part of the completion text.
+    Completion(CompletionResponseBody),
+    /// The ping chunk.
+    Ping(PingChunk),
+    /// The chunk of an event type unknown to this crate.
+    Unknown(UnknownChunk),
+}
+
+impl Display for CompletionChunk {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | CompletionChunk::Completion(completion) => {
+                write!(f, "{}", completion)
+            },
+            | CompletionChunk::Ping(ping) => {
+                write!(f, "{}", ping)
+            },
+            | CompletionChunk::Unknown(unknown) => {
+                write!(
+                    f,
+                    "event: {}\ndata: {}",
+                    unknown.event, unknown.data
+                )
+            },
+        }
+    }
+}
+
+impl ParseChunk for CompletionChunk {
+    fn parse_chunk(event: &SseEvent) -> Result<Self, StreamError> {
+        let data = event.data.as_str();
+
+        match event.event.as_str() {
+            | "completion" => Ok(CompletionChunk::Completion(
+                serde_json::from_str(data)?,
+            )),
+            | "ping" => Ok(CompletionChunk::Ping(
+                serde_json::from_str(data)?,
+            )),
+            | "error" => Err(serde_json::from_str::<ApiErrorResponse>(
+                data,
+            )?
+            .into()),
+            // Keep the chunk of an unknown event type for forward compatibility.
+            | _ => Ok(CompletionChunk::Unknown(
+                UnknownChunk::new(event.event.as_str(), data),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::chunk_stream::ChunkStream;
+    use crate::messages::{ClaudeModel, StopReason};
+
+    #[tokio::test]
+    async fn next() {
+        use futures_util::StreamExt;
+
+        let source = r#"event: completion
+data: {"type": "completion", "completion": " Hello", "stop_reason": null, "model": "claude-2.1"}
+
+event: ping
+data: {"type": "ping"}
+
+event: completion
+data: {"type": "completion", "completion": "!", "stop_reason": "stop_sequence", "model": "claude-2.1"}
+
+event: error
+data: {"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}
+
+"#;
+
+        let input_stream = futures_util::stream::iter(vec![Ok(
+            bytes::Bytes::from(source),
+        )]);
+
+        let mut chunk_stream =
+            ChunkStream::<_, CompletionChunk>::new(input_stream);
+
+        assert_eq!(
+            chunk_stream
+                .next()
+                .await
+                .unwrap()
+                .unwrap(),
+            CompletionChunk::Completion(CompletionResponseBody {
+                completion: " Hello".to_string(),
+                model: ClaudeModel::Claude21,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            chunk_stream
+                .next()
+                .await
+                .unwrap()
+                .unwrap(),
+            CompletionChunk::Ping(PingChunk::new())
+        );
+        assert_eq!(
+            chunk_stream
+                .next()
+                .await
+                .unwrap()
+                .unwrap(),
+            CompletionChunk::Completion(CompletionResponseBody {
+                completion: "!".to_string(),
+                stop_reason: Some(StopReason::StopSequence),
+                model: ClaudeModel::Claude21,
+                ..Default::default()
+            })
+        );
+        assert!(matches!(
+            chunk_stream.next().await.unwrap(),
+            Err(StreamError::ApiError { .. })
+        ));
+        assert!(chunk_stream.next().await.is_none());
+    }
+
+    #[test]
+    fn display_unknown() {
+        assert_eq!(
+            CompletionChunk::Unknown(UnknownChunk::new("unknown", "{}"))
+                .to_string(),
+            "event: unknown\ndata: {}"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::completions::Prompt;
+use crate::macros::impl_display_for_serialize;
+use crate::messages::{
+    ClaudeModel, MaxTokens, Metadata, StopSequence, StreamOption,
+    Temperature, TopK, TopP,
+};
+
+/// The request body for the legacy Text Completions API.
+///
+/// See also [the text completions API reference](https://docs.anthropic.com/claude/reference/complete_post).
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct CompletionRequestBody {
+    /// The model that will complete your prompt.
+    pub model: ClaudeModel,
+    /// The prompt that you want Claude to complete.
+    ///
+    /// The prompt must be formatted with alternating `\n\nHuman:` and `\n\nAssistant:` conversational turns, see [`Prompt::from_messages`].
+    pub prompt: Prompt,
+    /// The maximum number of tokens to generate before stopping.
+    ///
+    /// Note that our models may stop before reaching this maximum. This parameter only specifies the absolute maximum number of tokens to generate.
+    pub max_tokens_to_sample: MaxTokens,
+    /// Sequences that will cause the model to stop generating.
+    ///
+    /// Our models stop on `\n\nHuman:`, and may include additional built-in stop sequences in the future.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<Vec<StopSequence>>,
+    /// Amount of randomness injected into the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+    /// Use nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+    /// Only sample from the top K options for each subsequent token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<TopK>,
+    /// An object describing metadata about the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Whether to incrementally stream the response using server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<StreamOption>,
+}
+
+impl_display_for_serialize!(CompletionRequestBody);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let request_body = CompletionRequestBody {
+            model: ClaudeModel::Claude21,
+            prompt: Prompt::new("\n\nHuman: Hello, Claude\n\nAssistant:"),
+            max_tokens_to_sample: MaxTokens::new(256, &ClaudeModel::Claude21)
+                .unwrap(),
+            stream: Some(StreamOption::ReturnStream),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"model":"claude-2.1","prompt":"\n\nHuman: Hello, Claude\n\nAssistant:","max_tokens_to_sample":256,"stream":true}"#
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+};
+use crate::messages::{ClaudeModel, StopReason};
+use std::fmt::Display;
+
+/// The response body for the legacy Text Completions API.
+///
+/// It is also the data of the `completion` event in the stream, where `completion` is a part of the text.
+///
+/// See also [the text completions API reference](https://docs.anthropic.com/claude/reference/complete_post).
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct CompletionResponseBody {
+    /// Object type. For text completions, this is always "completion".
+    #[serde(rename = "type")]
+    pub _type: CompletionObjectType,
+    /// Unique object identifier.
+    #[serde(default)]
+    pub id: String,
+    /// The resulting completion up to and excluding the stop sequences.
+    pub completion: String,
+    /// The reason that we stopped.
+    ///
+    /// This may be one of the following values:
+    ///
+    /// "stop_sequence": we reached a stop sequence — either provided by you via the stop_sequences parameter, or a stop sequence built into the model
+    /// "max_tokens": we exceeded max_tokens_to_sample or the model's maximum
+    pub stop_reason: Option<StopReason>,
+    /// The model that handled the request.
+    pub model: ClaudeModel,
+}
+
+impl_display_for_serialize!(CompletionResponseBody);
+
+/// The object type of text completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompletionObjectType {
+    /// completion
+    Completion,
+}
+
+impl Default for CompletionObjectType {
+    fn default() -> Self {
+        Self::Completion
+    }
+}
+
+impl Display for CompletionObjectType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | CompletionObjectType::Completion => {
+                write!(f, "completion")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    CompletionObjectType,
+    Completion => "completion"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<CompletionResponseBody>(
+                r#"{"type":"completion","id":"compl_01","completion":" Hello!","stop_reason":"stop_sequence","model":"claude-2.1"}"#
+            )
+            .unwrap(),
+            CompletionResponseBody {
+                _type: CompletionObjectType::Completion,
+                id: "compl_01".to_string(),
+                completion: " Hello!".to_string(),
+                stop_reason: Some(StopReason::StopSequence),
+                model: ClaudeModel::Claude21,
+            }
+        );
+    }
+}
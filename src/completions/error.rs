@@ -0,0 +1,15 @@
+use crate::{ApiError, ClientError};
+
+/// The error type for the text completions API.
+#[derive(Debug, thiserror::Error)]
+pub enum CompletionsError {
+    /// The client error.
+    #[error(transparent)]
+    ClientError(#[from] ClientError),
+    /// The API error.
+    #[error(transparent)]
+    ApiError(#[from] ApiError),
+    /// Stream option mismatch.
+    #[error("stream option mismatch")]
+    StreamOptionMismatch,
+}
@@ -0,0 +1,247 @@
+use crate::messages::{Content, ContentBlock, Message, Role, SystemPrompt};
+use crate::{ValidationError, ValidationResult};
+use std::fmt::Display;
+
+/// The prefix of a human turn in the prompt.
+pub const HUMAN_PROMPT: &str = "\n\nHuman:";
+
+/// The prefix of an assistant turn in the prompt.
+pub const AI_PROMPT: &str = "\n\nAssistant:";
+
+/// The prompt of the legacy Text Completions API.
+///
+/// See also [prompt validation](https://docs.anthropic.com/claude/reference/prompt-validation).
+///
+/// ## Example
+/// ```
+/// use clust::completions::Prompt;
+/// use clust::messages::Message;
+///
+/// let prompt = Prompt::from_messages(
+///     None,
+///     &[Message::user("Hello, Claude")],
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     prompt.to_string(),
+///     "\n\nHuman: Hello, Claude\n\nAssistant:"
+/// );
+/// ```
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct Prompt {
+    value: String,
+}
+
+impl Display for Prompt {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl From<String> for Prompt {
+    fn from(value: String) -> Self {
+        Self {
+            value,
+        }
+    }
+}
+
+impl From<&str> for Prompt {
+    fn from(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Prompt {
+    /// Creates a new prompt as is.
+    pub fn new<S>(value: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            value: value.into(),
+        }
+    }
+
+    /// Formats the messages into the legacy prompt with alternating `\n\nHuman:` and `\n\nAssistant:` turns.
+    ///
+    /// The system prompt is placed before the first human turn.
+    /// The prompt ends with `\n\nAssistant:` to let the model respond, unless the last message is an assistant message to prefill the response.
+    ///
+    /// ## Arguments
+    /// - `system` - The system prompt.
+    /// - `messages` - The messages starting with a user message and alternating between user and assistant.
+    ///
+    /// ## Errors
+    /// It returns a validation error when the messages are empty, do not alternate starting with a user message, or have non-text content.
+    pub fn from_messages(
+        system: Option<&SystemPrompt>,
+        messages: &[Message],
+    ) -> ValidationResult<Self, String> {
+        if messages.is_empty() {
+            return Err(ValidationError {
+                _type: "Prompt".to_string(),
+                expected: "At least one message is required.".to_string(),
+                actual: "no messages".to_string(),
+            });
+        }
+
+        let mut value = system
+            .map(|system| system.to_string())
+            .unwrap_or_default();
+
+        for (index, message) in messages.iter().enumerate() {
+            let expected_role = if index % 2 == 0 {
+                Role::User
+            } else {
+                Role::Assistant
+            };
+            if message.role != expected_role {
+                return Err(ValidationError {
+                    _type: "Prompt".to_string(),
+                    expected: "Messages must alternate between user and assistant, starting with user.".to_string(),
+                    actual: format!(
+                        "messages[{}].role: {}",
+                        index, message.role
+                    ),
+                });
+            }
+
+            let text = text_of(&message.content).ok_or_else(|| {
+                ValidationError {
+                    _type: "Prompt".to_string(),
+                    expected: "Messages must have only text content."
+                        .to_string(),
+                    actual: format!("messages[{}].content", index),
+                }
+            })?;
+
+            let prefix = match message.role {
+                | Role::User => HUMAN_PROMPT,
+                | Role::Assistant => AI_PROMPT,
+            };
+            value.push_str(prefix);
+            if !text.is_empty() {
+                value.push(' ');
+                value.push_str(&text);
+            }
+        }
+
+        // Let the model respond to the last human turn.
+        if messages.len() % 2 == 1 {
+            value.push_str(AI_PROMPT);
+        }
+
+        Ok(Self {
+            value,
+        })
+    }
+}
+
+impl TryFrom<Vec<Message>> for Prompt {
+    type Error = ValidationError<String>;
+
+    fn try_from(messages: Vec<Message>) -> Result<Self, Self::Error> {
+        Self::from_messages(None, &messages)
+    }
+}
+
+/// Returns the text of the content, or `None` if it has non-text blocks.
+fn text_of(content: &Content) -> Option<String> {
+    match content {
+        | Content::SingleText(text) => Some(text.clone()),
+        | Content::MultipleBlock(blocks) => blocks
+            .iter()
+            .map(|block| match block {
+                | ContentBlock::Text(text) => Some(text.text.as_str()),
+                | _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(|texts| texts.join("\n")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{ImageContentBlock, TextContentBlock};
+
+    #[test]
+    fn from_messages() {
+        let prompt = Prompt::from_messages(
+            Some(&SystemPrompt::new("You are a helpful assistant.")),
+            &[
+                Message::user("Hello"),
+                Message::assistant("Hi!"),
+                Message::user(vec![
+                    ContentBlock::Text(TextContentBlock::new("How are you?")),
+                    ContentBlock::Text(TextContentBlock::new(
+                        "Answer briefly.",
+                    )),
+                ]),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            prompt.to_string(),
+            "You are a helpful assistant.\n\nHuman: Hello\n\nAssistant: Hi!\n\nHuman: How are you?\nAnswer briefly.\n\nAssistant:"
+        );
+    }
+
+    #[test]
+    fn from_messages_with_prefill() {
+        let prompt: Prompt = vec![
+            Message::user("Say hello in JSON."),
+            Message::assistant("{"),
+        ]
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            prompt.to_string(),
+            "\n\nHuman: Say hello in JSON.\n\nAssistant: {"
+        );
+    }
+
+    #[test]
+    fn from_invalid_messages() {
+        assert!(Prompt::from_messages(None, &[]).is_err());
+        assert!(Prompt::from_messages(
+            None,
+            &[Message::assistant("Hi!")]
+        )
+        .is_err());
+        assert!(Prompt::from_messages(
+            None,
+            &[
+                Message::user("Hello"),
+                Message::user("Hello again"),
+            ]
+        )
+        .is_err());
+        assert!(Prompt::from_messages(
+            None,
+            &[Message::user(vec![
+                ContentBlock::Image(ImageContentBlock::default()),
+            ])]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&Prompt::new("\n\nHuman: Hi\n\nAssistant:"))
+                .unwrap(),
+            r#""\n\nHuman: Hi\n\nAssistant:""#
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use crate::completions::{CompletionChunk, CompletionsError};
+use crate::messages::StreamError;
+
+/// The result type for the text completions API.
+pub type CompletionsResult<T> = Result<T, CompletionsError>;
+
+/// The result type as stream item for the text completions API.
+pub type CompletionStreamResult = Result<CompletionChunk, StreamError>;
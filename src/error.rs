@@ -62,6 +62,53 @@ pub enum ClientError {
         error: serde_json::Error,
         text: String,
     },
+    /// Timed out waiting for the response headers of an API calling.
+    #[error("Timed out waiting for the response: {0:?}")]
+    ReadTimeout(std::time::Duration),
+    /// The API calling was cancelled by the cancellation token.
+    #[error("The request was cancelled")]
+    Cancelled,
+    /// Invalid extra header of the request options.
+    #[error("Invalid header: {0:?}")]
+    InvalidHeader(String),
+    /// The extra header of the request options is set by the client and can not be overridden.
+    #[error("Reserved header can not be overridden: {0}")]
+    ReservedHeader(String),
+    /// Failed to serialize the request body merged with the extra fields.
+    #[error("Failed to serialize request body as JSON: {0:?}")]
+    RequestBodySerializationFailed(serde_json::Error),
+    /// The ID in the request path is a dot segment: `.` or `..`, which would change the request target.
+    #[error("Invalid path segment: {0:?}")]
+    InvalidPathSegment(String),
+}
+
+/// The error of building the API client.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientBuilderError {
+    /// The API key is neither set nor found in the environment variable: `ANTHROPIC_API_KEY`.
+    #[error("API key is missing")]
+    MissingApiKey,
+    /// The environment variable is invalid.
+    #[error("Invalid environment variable: {0:?}")]
+    InvalidEnvVar(std::env::VarError),
+    /// The combination of the options is invalid.
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError<String>),
+    /// The name or value of the default header is invalid.
+    #[error("Invalid default header: {0}")]
+    InvalidHeader(String),
+    /// The default header is set by the client and can not be overridden.
+    #[error("Reserved header can not be overridden: {0}")]
+    ReservedHeader(String),
+    /// The URL of the proxy is invalid.
+    #[error("Invalid proxy: {0:?}")]
+    InvalidProxy(reqwest::Error),
+    /// The prebuilt HTTP client conflicts with the HTTP client options.
+    #[error("HTTP client options can not be combined with a prebuilt HTTP client")]
+    ConflictingHttpClient,
+    /// Failed to build the HTTP client.
+    #[error("Failed to build HTTP client: {0:?}")]
+    HttpClientBuildFailed(reqwest::Error),
 }
 
 /// The error of the API server.
@@ -69,6 +116,8 @@ pub enum ClientError {
 pub struct ApiError {
     /// The HTTP status code of the response.
     pub status: StatusCode,
+    /// The unique ID of the request by the `request-id` header, which is useful to contact support.
+    pub request_id: Option<String>,
     /// The type of the error.
     pub _type: ApiErrorType,
     /// The response body of the error.
@@ -84,7 +133,11 @@ impl Display for ApiError {
             f,
             "API error: ({}) {}: {}",
             self.status, self._type, self.response,
-        )
+        )?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request-id: {})", request_id)?;
+        }
+        Ok(())
     }
 }
 
@@ -92,11 +145,13 @@ impl ApiError {
     /// Creates a new API error.
     pub(crate) fn new(
         status: StatusCode,
+        request_id: Option<String>,
         response: ApiErrorResponse,
     ) -> Self {
         let _type = ApiErrorType::from(status);
         Self {
             status,
+            request_id,
             _type,
             response,
         }
@@ -199,3 +254,26 @@ impl From<StatusCode> for ApiErrorType {
         }
     }
 }
+
+impl ApiErrorType {
+    /// Creates the API error type from the `type` of the error body, e.g. `overloaded_error`.
+    ///
+    /// ## Arguments
+    /// - `error_type` - The type of the error body.
+    /// - `status` - The HTTP status code used for an unknown error type.
+    pub fn from_error_type(
+        error_type: &str,
+        status: StatusCode,
+    ) -> Self {
+        match error_type {
+            | "invalid_request_error" => Self::InvalidRequestError,
+            | "authentication_error" => Self::AuthenticationError,
+            | "permission_error" => Self::PermissionError,
+            | "not_found_error" => Self::NotFoundError,
+            | "rate_limit_error" => Self::RateLimitError,
+            | "api_error" => Self::ApiError,
+            | "overloaded_error" => Self::OverloadedError,
+            | _ => Self::Unknown(status),
+        }
+    }
+}
@@ -4,6 +4,19 @@
 //! - [Messages](`crate::messages`)
 //!     - [x] [Create a Message](https://docs.anthropic.com/claude/reference/messages_post)
 //!     - [x] [Streaming Messages](https://docs.anthropic.com/claude/reference/messages-streaming)
+//!     - [x] [Count Message tokens](https://docs.anthropic.com/en/api/messages-count-tokens)
+//! - [Message Batches](`crate::batches`)
+//!     - [x] [Create a Message Batch](https://docs.anthropic.com/en/api/creating-message-batches)
+//!     - [x] [Retrieve a Message Batch](https://docs.anthropic.com/en/api/retrieving-message-batches)
+//!     - [x] [Retrieve Message Batch Results](https://docs.anthropic.com/en/api/retrieving-message-batch-results)
+//!     - [x] [List Message Batches](https://docs.anthropic.com/en/api/listing-message-batches)
+//!     - [x] [Cancel a Message Batch](https://docs.anthropic.com/en/api/canceling-message-batches)
+//! - [Models](`crate::models`)
+//!     - [x] [List Models](https://docs.anthropic.com/en/api/models-list)
+//!     - [x] [Get a Model](https://docs.anthropic.com/en/api/models)
+//! - [Text Completions (legacy)](`crate::completions`)
+//!     - [x] [Create a Text Completion](https://docs.anthropic.com/claude/reference/complete_post)
+//!     - [x] [Streaming Text Completions](https://docs.anthropic.com/claude/reference/streaming)
 //!
 //! ## Usage
 //!
@@ -34,7 +47,7 @@
 //!     let messages = vec![Message::user(
 //!         "Where is the capital of France?",
 //!     )];
-//!     let max_tokens = MaxTokens::new(1024, model)?;
+//!     let max_tokens = MaxTokens::new(1024, &model)?;
 //!     let system_prompt = SystemPrompt::new("You are an excellent AI assistant.");
 //!     let request_body = MessagesRequestBody {
 //!         model,
@@ -71,7 +84,7 @@
 //! use clust::messages::MessagesRequestBody;
 //! use clust::messages::SystemPrompt;
 //! use clust::messages::StreamOption;
-//! use clust::messages::StreamChunk;
+//! use clust::messages::MessageAccumulator;
 //! use clust::Client;
 //!
 //! use futures_util::StreamExt;
@@ -86,7 +99,7 @@
 //!     let messages = vec![Message::user(
 //!         "Where is the capital of France?",
 //!     )];
-//!     let max_tokens = MaxTokens::new(1024, model)?;
+//!     let max_tokens = MaxTokens::new(1024, &model)?;
 //!     let system_prompt = SystemPrompt::new("You are an excellent AI assistant.");
 //!     let request_body = MessagesRequestBody {
 //!         model,
@@ -102,7 +115,7 @@
 //!         .create_a_message_stream(request_body)
 //!         .await?;
 //!
-//!     let mut buffer = String::new();
+//!     let mut accumulator = MessageAccumulator::new();
 //!
 //!     // 4. Poll the stream.
 //!     // NOTE: The `futures_util::StreamExt` run on the single thread.
@@ -110,13 +123,8 @@
 //!         match chunk {
 //!             | Ok(chunk) => {
 //!                 println!("Chunk:\n{}", chunk);
-//!                 match chunk {
-//!                     | StreamChunk::ContentBlockDelta(content_block_delta) => {
-//!                         // Buffer message delta.
-//!                         buffer.push_str(&content_block_delta.delta.text);
-//!                     }
-//!                     | _ => {}
-//!                 }
+//!                 // Accumulate the chunk into the message.
+//!                 accumulator.push(&chunk)?;
 //!             }
 //!             | Err(error) => {
 //!                 eprintln!("Chunk error:\n{:?}", error);
@@ -124,7 +132,7 @@
 //!         }
 //!     }
 //!
-//!     println!("Result:\n{}", buffer);
+//!     println!("Result:\n{}", accumulator.into_response()?);
 //!
 //!     Ok(())
 //! }
@@ -143,24 +151,52 @@
 //! See also [examples](./examples) for more details.
 
 mod api_key;
+mod base_url;
+mod beta;
 mod client;
+mod client_builder;
 mod error;
+mod pagination;
+mod rate_limit;
+mod request_options;
+mod response;
 mod result;
+mod retry;
 mod version;
 
 pub(crate) mod macros;
 
+pub mod batches;
+pub mod completions;
 pub mod messages;
+pub mod models;
+pub mod sse;
 
 pub use api_key::ApiKey;
+pub use base_url::BaseUrl;
+pub use beta::BetaFeature;
+pub use beta::BetaFeatures;
 pub use client::Client;
+pub use client_builder::ClientBuilder;
 pub use error::ApiError;
 pub use error::ApiErrorBody;
 pub use error::ApiErrorResponse;
 pub use error::ApiErrorType;
+pub use error::ClientBuilderError;
 pub use error::ClientError;
 pub use error::ValidationError;
+pub use pagination::Page;
+pub use pagination::PaginationQuery;
+pub use rate_limit::RateLimit;
+pub use rate_limit::RateLimiter;
+pub use rate_limit::RateLimits;
+pub use request_options::CancellationToken;
+pub use request_options::RequestOptions;
+pub use response::ApiResponse;
+pub use response::ResponseMetadata;
 pub use result::ValidationResult;
+pub use retry::HttpErrorKind;
+pub use retry::RetryPolicy;
 pub use version::Version;
 
 pub use futures_core;
@@ -3,6 +3,7 @@
 /// ## Arguments
 /// - `$enum_name`: The name of the enum.
 /// - `$($variant:ident => $str:expr),*`: The variants of the enum and their corresponding string representations.
+/// - `$fallback:ident`: (Optional) The variant with a [`String`] that holds an unknown string, e.g. `; Custom`.
 macro_rules! impl_enum_string_serialization {
     ($enum_name:ident, $($variant:ident => $str:expr),*) => {
         impl serde::Serialize for $enum_name {
@@ -48,6 +49,55 @@ macro_rules! impl_enum_string_serialization {
                     }
                 }
 
+                deserializer.deserialize_str(EnumVisitor)
+            }
+        }
+    };
+    ($enum_name:ident, $($variant:ident => $str:expr),*; $fallback:ident) => {
+        impl serde::Serialize for $enum_name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                match self {
+                    $(
+                        $enum_name::$variant => serializer.serialize_str($str),
+                    )*
+                    $enum_name::$fallback(value) => serializer.serialize_str(value),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct EnumVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for EnumVisitor {
+                    type Value = $enum_name;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter,
+                    ) -> std::fmt::Result {
+                        formatter.write_str(concat!("a string representing a ", stringify!($enum_name)))
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<$enum_name, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            $(
+                                $str => Ok($enum_name::$variant),
+                            )*
+                            _ => Ok($enum_name::$fallback(value.to_string())),
+                        }
+                    }
+                }
+
                 deserializer.deserialize_str(EnumVisitor)
             }
         }
@@ -56,6 +106,60 @@ macro_rules! impl_enum_string_serialization {
 
 pub(crate) use impl_enum_string_serialization;
 
+/// Implements [`std::fmt::Display`], [`From<&str>`], [`From<String>`], [`serde::Serialize`] and [`serde::Deserialize`]
+/// for an enum with corresponding string variants and a fallback variant that holds an unknown string,
+/// so that the string of each variant is written only once.
+///
+/// ## Arguments
+/// - `$enum_name`: The name of the enum.
+/// - `$($variant:ident => $str:expr),*`: The variants of the enum and their corresponding string representations.
+/// - `$fallback:ident`: The variant with a [`String`] that holds an unknown string, e.g. `; Custom`.
+macro_rules! impl_enum_string_conversion {
+    ($enum_name:ident, $($variant:ident => $str:expr),*; $fallback:ident) => {
+        impl std::fmt::Display for $enum_name {
+            fn fmt(
+                &self,
+                f: &mut std::fmt::Formatter<'_>,
+            ) -> std::fmt::Result {
+                match self {
+                    $(
+                        $enum_name::$variant => write!(f, "{}", $str),
+                    )*
+                    $enum_name::$fallback(value) => write!(f, "{}", value),
+                }
+            }
+        }
+
+        impl From<&str> for $enum_name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $(
+                        $str => $enum_name::$variant,
+                    )*
+                    _ => $enum_name::$fallback(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $enum_name {
+            fn from(value: String) -> Self {
+                match $enum_name::from(value.as_str()) {
+                    $enum_name::$fallback(_) => $enum_name::$fallback(value),
+                    known => known,
+                }
+            }
+        }
+
+        $crate::macros::impl_enum_string_serialization!(
+            $enum_name,
+            $($variant => $str),*;
+            $fallback
+        );
+    };
+}
+
+pub(crate) use impl_enum_string_conversion;
+
 /// Implements [`serde::Serialize`], [`serde::Deserialize`] and [`From`]
 /// for an enum with corresponding struct variants by indicating the tag field.
 ///
@@ -338,6 +442,63 @@ mod tests {
         assert_eq!(deserialized, test);
     }
     
+    #[test]
+    fn test_impl_enum_string_conversion() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        enum TestEnum {
+            A,             // "a"
+            B,             // "b"
+            Other(String), // any other string
+        }
+
+        impl_enum_string_conversion!(TestEnum, A => "a", B => "b"; Other);
+
+        assert_eq!(TestEnum::A.to_string(), "a");
+        assert_eq!(TestEnum::Other("c".to_string()).to_string(), "c");
+
+        assert_eq!(TestEnum::from("b"), TestEnum::B);
+        assert_eq!(TestEnum::from("b".to_string()), TestEnum::B);
+        assert_eq!(
+            TestEnum::from("c".to_string()),
+            TestEnum::Other("c".to_string())
+        );
+
+        let serialized = serde_json::to_string(&TestEnum::B).unwrap();
+        assert_eq!(serialized, "\"b\"");
+
+        let deserialized: TestEnum = serde_json::from_str("\"c\"").unwrap();
+        assert_eq!(deserialized, TestEnum::Other("c".to_string()));
+    }
+
+    #[test]
+    fn test_impl_enum_string_serialization_with_fallback() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        enum TestEnum {
+            A,             // "a"
+            B,             // "b"
+            Other(String), // any other string
+        }
+
+        impl_enum_string_serialization!(TestEnum, A => "a", B => "b"; Other);
+
+        let test = TestEnum::A;
+        let serialized = serde_json::to_string(&test).unwrap();
+        assert_eq!(serialized, "\"a\"");
+
+        let deserialized: TestEnum = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, test);
+
+        let test = TestEnum::Other("c".to_string());
+        let serialized = serde_json::to_string(&test).unwrap();
+        assert_eq!(serialized, "\"c\"");
+
+        let deserialized: TestEnum = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, test);
+
+        let deserialized: TestEnum = serde_json::from_str("\"b\"").unwrap();
+        assert_eq!(deserialized, TestEnum::B);
+    }
+
     #[test]
     fn test_impl_enum_struct_serialization() {
         #[derive(Debug, Clone, PartialEq)]
@@ -1,14 +1,19 @@
 //! The [Messages API](https://docs.anthropic.com/claude/reference/messages_post) implementations.
 
-mod chunk_stream;
+mod cache_control;
+mod citation;
 mod claude_model;
 mod content;
+mod count_tokens;
 mod error;
 mod max_tokens;
+mod media;
 mod message;
+mod message_accumulator;
 mod messages_request_body;
 mod messages_response_body;
 mod metadata;
+mod model_capabilities;
 mod result;
 mod role;
 mod stop_reason;
@@ -17,31 +22,68 @@ mod stream_chunk;
 mod stream_option;
 mod system_prompt;
 mod temperature;
+mod thinking;
+mod tool;
+mod tool_use_accumulator;
 mod top_k;
 mod top_p;
 mod usage;
 
 pub(crate) mod api;
+pub(crate) mod chunk_stream;
 
+pub use cache_control::CacheControl;
+pub use cache_control::CacheControlType;
+pub use citation::CharLocationCitation;
+pub use citation::Citation;
+pub use citation::CitationType;
+pub use citation::ContentBlockLocationCitation;
+pub use citation::PageLocationCitation;
 pub use claude_model::ClaudeModel;
+pub use content::Base64ImageSource;
+pub use content::Base64PdfSource;
+pub use content::CitationsConfig;
+pub use content::CitationsDeltaContentBlock;
 pub use content::Content;
 pub use content::ContentBlock;
 pub use content::ContentType;
+pub use content::DocumentContentBlock;
+pub use content::DocumentContentSource;
+pub use content::DocumentMediaType;
+pub use content::DocumentSourceType;
 pub use content::ImageContentBlock;
 pub use content::ImageContentSource;
 pub use content::ImageMediaType;
 pub use content::ImageSourceType;
+pub use content::InputJsonDeltaContentBlock;
+pub use content::PlainTextSource;
+pub use content::RedactedThinkingContentBlock;
+pub use content::SignatureDeltaContentBlock;
 pub use content::TextContentBlock;
 pub use content::TextDeltaContentBlock;
+pub use content::ThinkingContentBlock;
+pub use content::ThinkingDeltaContentBlock;
+pub use content::ToolResultContentBlock;
+pub use content::ToolUseContentBlock;
+pub use content::UrlImageSource;
+pub use count_tokens::CountTokensRequestBody;
+pub use count_tokens::CountTokensResponseBody;
+pub use error::ContentSourceError;
 pub use error::MessagesError;
 pub use error::StreamError;
 pub use max_tokens::MaxTokens;
 pub use message::Message;
+pub use message_accumulator::AccumulateMessage;
+pub use message_accumulator::MessageAccumulator;
+pub use message_accumulator::MessageStreamExt;
 pub use messages_request_body::MessagesRequestBody;
 pub use messages_response_body::MessageObjectType;
 pub use messages_response_body::MessagesResponseBody;
 pub use metadata::Metadata;
 pub use metadata::UserId;
+pub use model_capabilities::ModelCapabilities;
+pub use model_capabilities::ModelPricing;
+pub use model_capabilities::ModelRegistry;
 pub use result::ChunkStreamResult;
 pub use result::MessagesResult;
 pub use role::Role;
@@ -58,9 +100,22 @@ pub use stream_chunk::PingChunk;
 pub use stream_chunk::StreamChunk;
 pub use stream_chunk::StreamChunkType;
 pub use stream_chunk::StreamStop;
+pub use stream_chunk::UnknownChunk;
 pub use stream_option::StreamOption;
 pub use system_prompt::SystemPrompt;
+pub use system_prompt::SystemPromptBuilder;
 pub use temperature::Temperature;
+pub use thinking::DisabledThinkingConfig;
+pub use thinking::EnabledThinkingConfig;
+pub use thinking::ThinkingConfig;
+pub use thinking::ThinkingConfigType;
+pub use tool::AnyToolChoice;
+pub use tool::AutoToolChoice;
+pub use tool::SpecificToolChoice;
+pub use tool::ToolChoice;
+pub use tool::ToolChoiceType;
+pub use tool::ToolDefinition;
+pub use tool_use_accumulator::ToolUseAccumulator;
 pub use top_k::TopK;
 pub use top_p::TopP;
 pub use usage::Usage;
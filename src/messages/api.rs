@@ -1,17 +1,34 @@
 use crate::messages::chunk_stream::ChunkStream;
 use crate::messages::{
-    ChunkStreamResult, MessagesError, MessagesRequestBody,
-    MessagesResponseBody, MessagesResult, StreamOption,
+    ChunkStreamResult, CountTokensRequestBody, CountTokensResponseBody,
+    MessagesError, MessagesRequestBody, MessagesResponseBody, MessagesResult,
+    StreamChunk, StreamOption,
 };
-use crate::ApiError;
+use crate::rate_limit::RequestCost;
+use crate::response::{
+    read_error_response, read_response, read_response_with_metadata,
+};
+use crate::ApiResponse;
 use crate::Client;
-use crate::ClientError;
+use crate::RequestOptions;
 use futures_core::Stream;
+use reqwest::Method;
 
 pub(crate) async fn create_a_message(
     client: &Client,
     request_body: MessagesRequestBody,
+    options: &RequestOptions,
 ) -> MessagesResult<MessagesResponseBody> {
+    create_a_message_with_response(client, request_body, options)
+        .await
+        .map(ApiResponse::into_body)
+}
+
+pub(crate) async fn create_a_message_with_response(
+    client: &Client,
+    request_body: MessagesRequestBody,
+    options: &RequestOptions,
+) -> MessagesResult<ApiResponse<MessagesResponseBody>> {
     // Validate stream option.
     if let Some(stream) = &request_body.stream {
         if *stream != StreamOption::ReturnOnce {
@@ -20,56 +37,58 @@ pub(crate) async fn create_a_message(
     }
 
     // Send the request.
-    let response = client
-        .post("https://api.anthropic.com/v1/messages")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(ClientError::HttpRequestError)?;
+    let request = options.apply(
+        client.request(
+            Method::POST,
+            "/v1/messages",
+            options.timeout().or(client.timeout),
+            client.beta_features(options.beta_features()),
+        ),
+        &request_body,
+    )?;
+    let cost = RequestCost::estimate(&request_body);
 
-    // Check the response status code.
-    let status_code = response.status();
-
-    // Read the response text.
-    let response_text = response
-        .text()
+    options
+        .cancellable(async {
+            let response = client
+                .send_with_cost(request, Some(&cost))
+                .await?;
+            read_response_with_metadata(response).await
+        })
         .await
-        .map_err(ClientError::ReadResponseTextFailed)?;
+}
 
-    // Ok
-    if status_code.is_success() {
-        // Deserialize the response.
-        serde_json::from_str(&response_text).map_err(|error| {
-            {
-                ClientError::ResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            }
-            .into()
-        })
-    }
-    // Error
-    else {
-        // Deserialize the error response.
-        let error_response =
-            serde_json::from_str(&response_text).map_err(|error| {
-                ClientError::ErrorResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            })?;
+pub(crate) async fn count_tokens(
+    client: &Client,
+    request_body: MessagesRequestBody,
+    options: &RequestOptions,
+) -> MessagesResult<CountTokensResponseBody> {
+    // Send the request without the generation parameters.
+    let request = options.apply(
+        client.request(
+            Method::POST,
+            "/v1/messages/count_tokens",
+            options.timeout().or(client.timeout),
+            client.beta_features(options.beta_features()),
+        ),
+        &CountTokensRequestBody::from(request_body),
+    )?;
 
-        Err(ApiError::new(status_code, error_response).into())
-    }
+    options
+        .cancellable(async {
+            let response = client.send(request).await?;
+            read_response(response).await
+        })
+        .await
 }
 
 pub(crate) async fn create_a_message_stream(
     client: &Client,
     request_body: MessagesRequestBody,
+    options: &RequestOptions,
 ) -> MessagesResult<impl Stream<Item = ChunkStreamResult>> {
     // Validate stream option.
-    if let None = &request_body.stream {
+    if request_body.stream.is_none() {
         return Err(MessagesError::StreamOptionMismatch);
     }
     if let Some(stream) = &request_body.stream {
@@ -79,12 +98,19 @@ pub(crate) async fn create_a_message_stream(
     }
 
     // Send the request.
-    let response = client
-        .post("https://api.anthropic.com/v1/messages")
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(ClientError::HttpRequestError)?;
+    let request = options.apply(
+        client.request(
+            Method::POST,
+            "/v1/messages",
+            options.timeout().or(client.stream_timeout),
+            client.beta_features(options.beta_features()),
+        ),
+        &request_body,
+    )?;
+    let cost = RequestCost::estimate(&request_body);
+    let response = options
+        .cancellable(client.send_with_cost(request, Some(&cost)))
+        .await?;
 
     // Check the response status code.
     let status_code = response.status();
@@ -93,26 +119,12 @@ pub(crate) async fn create_a_message_stream(
     if status_code.is_success() {
         // Create a chunk stream from response bytes stream.
         let byte_stream = response.bytes_stream();
-        let chunk_stream = ChunkStream::new(byte_stream);
-        Ok(chunk_stream)
+        let chunk_stream =
+            ChunkStream::<_, StreamChunk>::new(byte_stream);
+        Ok(options.cancellable_stream(chunk_stream))
     }
     // Error
     else {
-        // Read the response text.
-        let response_text = response
-            .text()
-            .await
-            .map_err(ClientError::ReadResponseTextFailed)?;
-
-        // Deserialize the error response.
-        let error_response =
-            serde_json::from_str(&response_text).map_err(|error| {
-                ClientError::ErrorResponseDeserializationFailed {
-                    error,
-                    text: response_text,
-                }
-            })?;
-
-        Err(ApiError::new(status_code, error_response).into())
+        Err(read_error_response(response).await)
     }
 }
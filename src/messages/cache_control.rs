@@ -0,0 +1,98 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+};
+use std::fmt::Display;
+
+/// The cache control to mark a prompt caching breakpoint.
+///
+/// The prefix of the prompt up to and including the block with the cache control is cached, in the order of tools, system and messages.
+///
+/// See also [prompt caching](https://docs.anthropic.com/en/docs/build-with-claude/prompt-caching).
+///
+/// ## Example
+/// ```
+/// use clust::messages::{CacheControl, TextContentBlock};
+///
+/// let block = TextContentBlock::new("A long document.")
+///     .with_cache_control(CacheControl::ephemeral());
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct CacheControl {
+    /// The type of the cache control.
+    #[serde(rename = "type")]
+    pub _type: CacheControlType,
+}
+
+impl_display_for_serialize!(CacheControl);
+
+impl CacheControl {
+    /// Creates a new ephemeral cache control, which has a lifetime of 5 minutes.
+    pub fn ephemeral() -> Self {
+        Self {
+            _type: CacheControlType::Ephemeral,
+        }
+    }
+}
+
+/// The type of the cache control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheControlType {
+    /// ephemeral
+    Ephemeral,
+}
+
+impl Default for CacheControlType {
+    fn default() -> Self {
+        Self::Ephemeral
+    }
+}
+
+impl Display for CacheControlType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | CacheControlType::Ephemeral => {
+                write!(f, "ephemeral")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    CacheControlType,
+    Ephemeral => "ephemeral"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&CacheControl::ephemeral()).unwrap(),
+            r#"{"type":"ephemeral"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<CacheControl>(r#"{"type":"ephemeral"}"#)
+                .unwrap(),
+            CacheControl::ephemeral()
+        );
+    }
+}
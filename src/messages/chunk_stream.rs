@@ -1,120 +1,89 @@
+use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use bytes::{Buf, BytesMut};
-use futures_core::Stream;
+use futures_core::{ready, Stream};
 use pin_project::pin_project;
 
 use crate::messages::{ChunkStreamResult, StreamChunk, StreamError};
+use crate::sse::{SseEvent, SseStream};
 
 /// The stream item of the reqwest response.
 type ReqwestStreamItem = Result<bytes::Bytes, reqwest::Error>;
 
-/// The stream of message chunks with `tokio` backend.
+/// The chunk parsed from a server-sent event of the stream.
+pub(crate) trait ParseChunk: Sized {
+    /// Parses the chunk from the event, surfacing the error event as the stream error.
+    fn parse_chunk(event: &SseEvent) -> Result<Self, StreamError>;
+}
+
+impl ParseChunk for StreamChunk {
+    fn parse_chunk(event: &SseEvent) -> ChunkStreamResult {
+        StreamChunk::from_event(event).and_then(into_result)
+    }
+}
+
+/// The stream of chunks with `tokio` backend, e.g. message chunks.
 #[pin_project]
-pub(crate) struct ChunkStream<S>
+pub(crate) struct ChunkStream<S, C>
 where
     S: Stream<Item = ReqwestStreamItem> + Unpin,
+    C: ParseChunk,
 {
     #[pin]
-    stream: S,
-    buffer: BytesMut,
+    stream: SseStream<S>,
+    /// The type of chunks.
+    chunk: PhantomData<C>,
 }
 
-impl<S> ChunkStream<S>
+impl<S, C> ChunkStream<S, C>
 where
     S: Stream<Item = ReqwestStreamItem> + Unpin,
+    C: ParseChunk,
 {
     /// Create a new chunk stream.
     pub fn new(stream: S) -> Self {
         ChunkStream {
-            stream,
-            buffer: BytesMut::new(),
+            stream: SseStream::new(stream),
+            chunk: PhantomData,
         }
     }
 }
 
-impl<S> Stream for ChunkStream<S>
+impl<S, C> Stream for ChunkStream<S, C>
 where
     S: Stream<Item = ReqwestStreamItem> + Unpin,
+    C: ParseChunk,
 {
-    type Item = ChunkStreamResult;
+    type Item = Result<C, StreamError>;
 
     fn poll_next(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        let mut this = self.project();
-
-        loop {
-            if let Some(position) = this
-                .buffer
-                .iter()
-                .position(|b| *b == b'\n')
-            {
-                if let Some(end) = this.buffer[position + 1..]
-                    .iter()
-                    .position(|b| *b == b'\n')
-                {
-                    let chunk_end = position + end + 2;
-                    let chunk = this
-                        .buffer
-                        .split_to(chunk_end)
-                        .to_vec();
-
-                    // Skip the newline.
-                    this.buffer.advance(1);
-
-                    // Check if the chunk is not empty.
-                    if chunk
-                        .iter()
-                        .any(|b| *b != b'\r' && *b != b'\n')
-                    {
-                        let chunk = String::from_utf8(chunk)
-                            .map_err(StreamError::StringDecodingError)?;
-
-                        let chunk = StreamChunk::parse(&chunk)?;
-                        return Poll::Ready(Some(Ok(chunk)));
-                    }
-                }
-            }
-
-            match this
-                .stream
-                .as_mut()
-                .poll_next(cx)
-            {
-                // The stream has more data.
-                | Poll::Ready(Some(Ok(chunk))) => {
-                    this.buffer.extend(&chunk);
-                    // Continue to the next iteration of the loop.
-                },
-                // The stream has an error.
-                | Poll::Ready(Some(Err(error))) => {
-                    return Poll::Ready(Some(Err(StreamError::ReqwestError(
-                        error,
-                    ))));
-                },
-                // The stream has no more data.
-                | Poll::Ready(None) => {
-                    return if this.buffer.is_empty() {
-                        Poll::Ready(None)
-                    } else {
-                        let remaining = this.buffer.split_off(0);
-                        let remaining =
-                            String::from_utf8(remaining.to_vec())
-                                .map_err(StreamError::StringDecodingError)?;
-                        let chunk = StreamChunk::parse(&remaining)?;
-                        Poll::Ready(Some(Ok(chunk)))
-                    };
-                },
-                // The stream has no more data for now.
-                | Poll::Pending => return Poll::Pending,
-            }
+        let this = self.project();
+
+        match ready!(this.stream.poll_next(cx)) {
+            // The stream has the next event.
+            | Some(Ok(event)) => Poll::Ready(Some(C::parse_chunk(&event))),
+            // The stream has an error.
+            | Some(Err(error)) => {
+                Poll::Ready(Some(Err(StreamError::ReqwestError(error))))
+            },
+            // The stream has no more events.
+            | None => Poll::Ready(None),
         }
     }
 }
 
+/// Surfaces the error chunk as the stream error.
+fn into_result(chunk: StreamChunk) -> ChunkStreamResult {
+    match chunk {
+        | StreamChunk::Error(error) => Err(error.into()),
+        | chunk => Ok(chunk),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::messages::*;
@@ -151,7 +120,8 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             bytes::Bytes::from(source),
         )]);
 
-        let mut chunk_stream = ChunkStream::new(input_stream);
+        let mut chunk_stream =
+            ChunkStream::<_, StreamChunk>::new(input_stream);
 
         let chunk = chunk_stream
             .next()
@@ -174,6 +144,8 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
                         usage: Usage {
                             input_tokens: 25,
                             output_tokens: 1,
+                            cache_creation_input_tokens: None,
+                            cache_read_input_tokens: None,
                         },
                     }),
                 );
@@ -190,7 +162,10 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             | StreamChunk::ContentBlockStart(content_block_start) => {
                 assert_eq!(
                     content_block_start,
-                    ContentBlockStartChunk::new(0, "".into()),
+                    ContentBlockStartChunk::new(
+                        0,
+                        TextContentBlock::new(""),
+                    ),
                 );
             },
             | _ => panic!("unexpected chunk type"),
@@ -217,7 +192,10 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             | StreamChunk::ContentBlockDelta(content_block_delta) => {
                 assert_eq!(
                     content_block_delta,
-                    ContentBlockDeltaChunk::new(0, "Hello".into()),
+                    ContentBlockDeltaChunk::new(
+                        0,
+                        TextDeltaContentBlock::new("Hello"),
+                    ),
                 );
             },
             | _ => panic!("unexpected chunk type"),
@@ -232,7 +210,10 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             | StreamChunk::ContentBlockDelta(content_block_delta) => {
                 assert_eq!(
                     content_block_delta,
-                    ContentBlockDeltaChunk::new(0, "!".into()),
+                    ContentBlockDeltaChunk::new(
+                        0,
+                        TextDeltaContentBlock::new("!"),
+                    ),
                 );
             },
             | _ => panic!("unexpected chunk type"),
@@ -313,7 +294,8 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             bytes::Bytes::from(source),
         )]);
 
-        let mut chunk_stream = ChunkStream::new(input_stream);
+        let mut chunk_stream =
+            ChunkStream::<_, StreamChunk>::new(input_stream);
 
         let chunk = chunk_stream
             .next()
@@ -336,6 +318,8 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
                         usage: Usage {
                             input_tokens: 25,
                             output_tokens: 1,
+                            cache_creation_input_tokens: None,
+                            cache_read_input_tokens: None,
                         },
                     }),
                 );
@@ -352,7 +336,10 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             | StreamChunk::ContentBlockStart(content_block_start) => {
                 assert_eq!(
                     content_block_start,
-                    ContentBlockStartChunk::new(0, "".into()),
+                    ContentBlockStartChunk::new(
+                        0,
+                        TextContentBlock::new(""),
+                    ),
                 );
             },
             | _ => panic!("unexpected chunk type"),
@@ -379,7 +366,10 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             | StreamChunk::ContentBlockDelta(content_block_delta) => {
                 assert_eq!(
                     content_block_delta,
-                    ContentBlockDeltaChunk::new(0, "Hello".into()),
+                    ContentBlockDeltaChunk::new(
+                        0,
+                        TextDeltaContentBlock::new("Hello"),
+                    ),
                 );
             },
             | _ => panic!("unexpected chunk type"),
@@ -394,7 +384,10 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             | StreamChunk::ContentBlockDelta(content_block_delta) => {
                 assert_eq!(
                     content_block_delta,
-                    ContentBlockDeltaChunk::new(0, "!".into()),
+                    ContentBlockDeltaChunk::new(
+                        0,
+                        TextDeltaContentBlock::new("!"),
+                    ),
                 );
             },
             | _ => panic!("unexpected chunk type"),
@@ -443,4 +436,89 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             .await
             .is_none());
     }
+
+    #[tokio::test]
+    async fn next_with_error_and_unknown_event() {
+        use futures_util::StreamExt;
+
+        let source = r#"event: ping
+data: {"type": "ping"}
+
+event: future_event
+data: {"type": "future_event"}
+
+event: error
+data: {"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}
+
+"#;
+
+        let input_stream = futures_util::stream::iter(vec![Ok(
+            bytes::Bytes::from(source),
+        )]);
+
+        let mut chunk_stream =
+            ChunkStream::<_, StreamChunk>::new(input_stream);
+
+        assert_eq!(
+            chunk_stream
+                .next()
+                .await
+                .unwrap()
+                .unwrap(),
+            StreamChunk::Ping(PingChunk::new())
+        );
+
+        assert_eq!(
+            chunk_stream
+                .next()
+                .await
+                .unwrap()
+                .unwrap(),
+            StreamChunk::Unknown(UnknownChunk::new(
+                "future_event",
+                r#"{"type": "future_event"}"#
+            ))
+        );
+
+        match chunk_stream.next().await.unwrap() {
+            | Err(StreamError::ApiError {
+                _type,
+                response,
+            }) => {
+                assert_eq!(_type, crate::ApiErrorType::OverloadedError);
+                assert_eq!(response.error.message, "Overloaded");
+            },
+            | _ => panic!("unexpected result"),
+        }
+
+        assert!(chunk_stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn next_with_crlf_and_fragments() {
+        use futures_util::StreamExt;
+
+        let input_stream = futures_util::stream::iter(vec![
+            Ok(bytes::Bytes::from(": keep-alive\r\nevent: content_block_delta\r\n")),
+            Ok(bytes::Bytes::from("data:{\"type\": \"content_block_delta\", \"index\": 0, ")),
+            Ok(bytes::Bytes::from("\"delta\": {\"type\": \"text_delta\", \"text\": \"Hello\"}}\r")),
+            Ok(bytes::Bytes::from("\n\r\n")),
+        ]);
+
+        let mut chunk_stream =
+            ChunkStream::<_, StreamChunk>::new(input_stream);
+
+        assert_eq!(
+            chunk_stream
+                .next()
+                .await
+                .unwrap()
+                .unwrap(),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                TextDeltaContentBlock::new("Hello"),
+            ))
+        );
+        assert!(chunk_stream.next().await.is_none());
+    }
 }
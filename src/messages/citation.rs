@@ -0,0 +1,260 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+    impl_enum_struct_serialization,
+};
+use std::fmt::Display;
+
+/// The citation of a document in a text content block of the response.
+///
+/// It is returned when the document has citations enabled, see [`crate::messages::DocumentContentBlock::with_citations`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Citation {
+    /// The character range of a plain text document.
+    CharLocation(CharLocationCitation),
+    /// The page range of a PDF document.
+    PageLocation(PageLocationCitation),
+    /// The content block range of a custom content document.
+    ContentBlockLocation(ContentBlockLocationCitation),
+}
+
+impl Default for Citation {
+    fn default() -> Self {
+        Self::CharLocation(CharLocationCitation::default())
+    }
+}
+
+impl_enum_struct_serialization!(
+    Citation,
+    type,
+    CharLocation(CharLocationCitation, "char_location"),
+    PageLocation(PageLocationCitation, "page_location"),
+    ContentBlockLocation(
+        ContentBlockLocationCitation,
+        "content_block_location"
+    )
+);
+
+impl_display_for_serialize!(Citation);
+
+impl Citation {
+    /// Returns the cited text.
+    pub fn cited_text(&self) -> &str {
+        match self {
+            | Citation::CharLocation(citation) => &citation.cited_text,
+            | Citation::PageLocation(citation) => &citation.cited_text,
+            | Citation::ContentBlockLocation(citation) => {
+                &citation.cited_text
+            },
+        }
+    }
+
+    /// Returns the index of the cited document in the request.
+    pub fn document_index(&self) -> u32 {
+        match self {
+            | Citation::CharLocation(citation) => citation.document_index,
+            | Citation::PageLocation(citation) => citation.document_index,
+            | Citation::ContentBlockLocation(citation) => {
+                citation.document_index
+            },
+        }
+    }
+}
+
+/// The citation of the character range of a plain text document.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CharLocationCitation {
+    /// The citation type. It is always `char_location`.
+    #[serde(rename = "type")]
+    pub _type: CitationType,
+    /// The cited text.
+    pub cited_text: String,
+    /// The index of the cited document in the request.
+    pub document_index: u32,
+    /// The title of the cited document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_title: Option<String>,
+    /// The start index of the characters, inclusive and 0-indexed.
+    pub start_char_index: u32,
+    /// The end index of the characters, exclusive and 0-indexed.
+    pub end_char_index: u32,
+}
+
+impl Default for CharLocationCitation {
+    fn default() -> Self {
+        Self {
+            _type: CitationType::CharLocation,
+            cited_text: String::new(),
+            document_index: 0,
+            document_title: None,
+            start_char_index: 0,
+            end_char_index: 0,
+        }
+    }
+}
+
+impl_display_for_serialize!(CharLocationCitation);
+
+/// The citation of the page range of a PDF document.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PageLocationCitation {
+    /// The citation type. It is always `page_location`.
+    #[serde(rename = "type")]
+    pub _type: CitationType,
+    /// The cited text.
+    pub cited_text: String,
+    /// The index of the cited document in the request.
+    pub document_index: u32,
+    /// The title of the cited document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_title: Option<String>,
+    /// The start page number, inclusive and 1-indexed.
+    pub start_page_number: u32,
+    /// The end page number, exclusive and 1-indexed.
+    pub end_page_number: u32,
+}
+
+impl Default for PageLocationCitation {
+    fn default() -> Self {
+        Self {
+            _type: CitationType::PageLocation,
+            cited_text: String::new(),
+            document_index: 0,
+            document_title: None,
+            start_page_number: 1,
+            end_page_number: 1,
+        }
+    }
+}
+
+impl_display_for_serialize!(PageLocationCitation);
+
+/// The citation of the content block range of a custom content document.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContentBlockLocationCitation {
+    /// The citation type. It is always `content_block_location`.
+    #[serde(rename = "type")]
+    pub _type: CitationType,
+    /// The cited text.
+    pub cited_text: String,
+    /// The index of the cited document in the request.
+    pub document_index: u32,
+    /// The title of the cited document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_title: Option<String>,
+    /// The start index of the content blocks, inclusive and 0-indexed.
+    pub start_block_index: u32,
+    /// The end index of the content blocks, exclusive and 0-indexed.
+    pub end_block_index: u32,
+}
+
+impl Default for ContentBlockLocationCitation {
+    fn default() -> Self {
+        Self {
+            _type: CitationType::ContentBlockLocation,
+            cited_text: String::new(),
+            document_index: 0,
+            document_title: None,
+            start_block_index: 0,
+            end_block_index: 0,
+        }
+    }
+}
+
+impl_display_for_serialize!(ContentBlockLocationCitation);
+
+/// The type of the citation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CitationType {
+    /// char_location
+    CharLocation,
+    /// page_location
+    PageLocation,
+    /// content_block_location
+    ContentBlockLocation,
+}
+
+impl Default for CitationType {
+    fn default() -> Self {
+        Self::CharLocation
+    }
+}
+
+impl Display for CitationType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | CitationType::CharLocation => {
+                write!(f, "char_location")
+            },
+            | CitationType::PageLocation => {
+                write!(f, "page_location")
+            },
+            | CitationType::ContentBlockLocation => {
+                write!(f, "content_block_location")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    CitationType,
+    CharLocation => "char_location",
+    PageLocation => "page_location",
+    ContentBlockLocation => "content_block_location"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<Citation>(
+                r#"{"type":"char_location","cited_text":"The grass is green.","document_index":0,"document_title":"Facts","start_char_index":0,"end_char_index":20}"#
+            )
+            .unwrap(),
+            Citation::CharLocation(CharLocationCitation {
+                _type: CitationType::CharLocation,
+                cited_text: "The grass is green.".to_string(),
+                document_index: 0,
+                document_title: Some("Facts".to_string()),
+                start_char_index: 0,
+                end_char_index: 20,
+            })
+        );
+
+        let citation = serde_json::from_str::<Citation>(
+            r#"{"type":"page_location","cited_text":"Terms","document_index":1,"document_title":null,"start_page_number":2,"end_page_number":3}"#,
+        )
+        .unwrap();
+        assert_eq!(citation.cited_text(), "Terms");
+        assert_eq!(citation.document_index(), 1);
+        assert!(matches!(
+            citation,
+            Citation::PageLocation(PageLocationCitation {
+                start_page_number: 2,
+                end_page_number: 3,
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            serde_json::from_str::<Citation>(
+                r#"{"type":"content_block_location","cited_text":"Sky","document_index":0,"start_block_index":1,"end_block_index":2}"#
+            )
+            .unwrap(),
+            Citation::ContentBlockLocation(_)
+        ));
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&Citation::default()).unwrap(),
+            r#"{"type":"char_location","cited_text":"","document_index":0,"start_char_index":0,"end_char_index":0}"#
+        );
+    }
+}
@@ -1,10 +1,29 @@
 use crate::macros::impl_enum_string_serialization;
+use crate::messages::{ModelCapabilities, ModelRegistry};
 use std::fmt::Display;
 
 /// The model that will complete your prompt.
 ///
 /// See [models](https://docs.anthropic.com/claude/docs/models-overview) for additional details and options.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// A model unknown to this crate, e.g. a newer model, is represented by [`ClaudeModel::Custom`] with its model ID.
+///
+/// ## Example
+/// ```
+/// use clust::messages::ClaudeModel;
+///
+/// let model = ClaudeModel::from("claude-3-opus-20240229");
+/// assert_eq!(model, ClaudeModel::Claude3Opus20240229);
+///
+/// let model = ClaudeModel::from("claude-next");
+/// assert_eq!(model, ClaudeModel::Custom("claude-next".to_string()));
+/// assert_eq!(model.to_string(), "claude-next");
+/// ```
+///
+/// ## NOTE
+/// Models are compared and hashed by the model ID,
+/// so [`ClaudeModel::Custom`] with a known model ID is equal to the known model.
+#[derive(Debug, Clone)]
 pub enum ClaudeModel {
     // Claude 3 Opus
     /// Claude 3 Opus at 2024/02/29.
@@ -15,6 +34,20 @@ pub enum ClaudeModel {
     // Claude 3 Haiku
     /// Claude 3 Haiku at 2024/03/07.
     Claude3Haiku20240307,
+    // Claude 2
+    /// Claude 2.1, a legacy model.
+    Claude21,
+    /// Claude 2.0, a legacy model.
+    Claude20,
+    // Claude Instant
+    /// Claude Instant 1.2, a legacy model.
+    ClaudeInstant12,
+    // Others
+    /// A model unknown to this crate with its model ID.
+    ///
+    /// Use [`ClaudeModel::new`] or [`From`] to create a model from the model ID,
+    /// which maps a known model ID to the known model.
+    Custom(String),
 }
 
 impl Default for ClaudeModel {
@@ -28,35 +61,106 @@ impl Display for ClaudeModel {
         &self,
         f: &mut std::fmt::Formatter<'_>,
     ) -> std::fmt::Result {
-        match self {
-            | ClaudeModel::Claude3Opus20240229 => {
-                write!(f, "claude-3-opus-20240229")
-            },
-            | ClaudeModel::Claude3Sonnet20240229 => {
-                write!(f, "claude-3-sonnet-20240229")
-            },
-            | ClaudeModel::Claude3Haiku20240307 => {
-                write!(f, "claude-3-haiku-20240307")
-            },
+        write!(f, "{}", self.model_id())
+    }
+}
+
+impl PartialEq for ClaudeModel {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.model_id() == other.model_id()
+    }
+}
+
+impl Eq for ClaudeModel {}
+
+impl std::hash::Hash for ClaudeModel {
+    fn hash<H: std::hash::Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        self.model_id().hash(state);
+    }
+}
+
+impl From<&str> for ClaudeModel {
+    fn from(value: &str) -> Self {
+        match value {
+            | "claude-3-opus-20240229" => Self::Claude3Opus20240229,
+            | "claude-3-sonnet-20240229" => Self::Claude3Sonnet20240229,
+            | "claude-3-haiku-20240307" => Self::Claude3Haiku20240307,
+            | "claude-2.1" => Self::Claude21,
+            | "claude-2.0" => Self::Claude20,
+            | "claude-instant-1.2" => Self::ClaudeInstant12,
+            | _ => Self::Custom(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for ClaudeModel {
+    fn from(value: String) -> Self {
+        match Self::from(value.as_str()) {
+            | Self::Custom(_) => Self::Custom(value),
+            | known => known,
         }
     }
 }
 
 impl ClaudeModel {
-    pub(crate) fn max_tokens(&self) -> u32 {
+    /// Creates a model from the model ID, which is a known model if the ID matches.
+    ///
+    /// ## Arguments
+    /// - `model_id` - The model ID, e.g. `claude-3-opus-20240229`.
+    pub fn new<S>(model_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::from(model_id.into())
+    }
+
+    /// Returns the model ID, e.g. `claude-3-opus-20240229`.
+    pub fn model_id(&self) -> &str {
         match self {
-            | ClaudeModel::Claude3Opus20240229 => 4096,
-            | ClaudeModel::Claude3Sonnet20240229 => 4096,
-            | ClaudeModel::Claude3Haiku20240307 => 4096,
+            | ClaudeModel::Claude3Opus20240229 => "claude-3-opus-20240229",
+            | ClaudeModel::Claude3Sonnet20240229 => "claude-3-sonnet-20240229",
+            | ClaudeModel::Claude3Haiku20240307 => "claude-3-haiku-20240307",
+            | ClaudeModel::Claude21 => "claude-2.1",
+            | ClaudeModel::Claude20 => "claude-2.0",
+            | ClaudeModel::ClaudeInstant12 => "claude-instant-1.2",
+            | ClaudeModel::Custom(model_id) => model_id,
         }
     }
+
+    /// Returns whether the model is unknown to this crate.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
+
+    /// Returns the capabilities of the model from the [`ModelRegistry`].
+    ///
+    /// It returns `None` for a model unknown to this crate that has not been registered.
+    pub fn capabilities(&self) -> Option<ModelCapabilities> {
+        ModelRegistry::get(self)
+    }
+
+    /// Returns the maximum number of output tokens if the capabilities are known.
+    pub(crate) fn max_tokens(&self) -> Option<u32> {
+        self.capabilities()
+            .map(|capabilities| capabilities.max_output_tokens)
+    }
 }
 
 impl_enum_string_serialization!(
     ClaudeModel,
     Claude3Opus20240229 => "claude-3-opus-20240229",
     Claude3Sonnet20240229 => "claude-3-sonnet-20240229",
-    Claude3Haiku20240307 => "claude-3-haiku-20240307"
+    Claude3Haiku20240307 => "claude-3-haiku-20240307",
+    Claude21 => "claude-2.1",
+    Claude20 => "claude-2.0",
+    ClaudeInstant12 => "claude-instant-1.2";
+    Custom
 );
 
 #[cfg(test)]
@@ -85,21 +189,72 @@ mod tests {
             ClaudeModel::Claude3Haiku20240307.to_string(),
             "claude-3-haiku-20240307"
         );
+        assert_eq!(
+            ClaudeModel::Claude21.to_string(),
+            "claude-2.1"
+        );
+        assert_eq!(
+            ClaudeModel::Claude20.to_string(),
+            "claude-2.0"
+        );
+        assert_eq!(
+            ClaudeModel::ClaudeInstant12.to_string(),
+            "claude-instant-1.2"
+        );
+        assert_eq!(
+            ClaudeModel::Custom("claude-next".to_string()).to_string(),
+            "claude-next"
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            ClaudeModel::new("claude-3-opus-20240229"),
+            ClaudeModel::Claude3Opus20240229
+        );
+        assert_eq!(
+            ClaudeModel::new("claude-next"),
+            ClaudeModel::Custom("claude-next".to_string())
+        );
+        assert!(ClaudeModel::new("claude-next").is_custom());
+        assert!(!ClaudeModel::Claude3Haiku20240307.is_custom());
+    }
+
+    #[test]
+    fn eq_by_model_id() {
+        use std::collections::HashSet;
+
+        let custom =
+            ClaudeModel::Custom("claude-3-opus-20240229".to_string());
+        assert_eq!(custom, ClaudeModel::Claude3Opus20240229);
+        assert_ne!(custom, ClaudeModel::Claude3Haiku20240307);
+        assert_eq!(custom.model_id(), "claude-3-opus-20240229");
+
+        let models = HashSet::from([
+            custom,
+            ClaudeModel::Claude3Opus20240229,
+        ]);
+        assert_eq!(models.len(), 1);
     }
 
     #[test]
     fn max_tokens() {
         assert_eq!(
             ClaudeModel::Claude3Opus20240229.max_tokens(),
-            4096
+            Some(4096)
         );
         assert_eq!(
             ClaudeModel::Claude3Sonnet20240229.max_tokens(),
-            4096
+            Some(4096)
         );
         assert_eq!(
             ClaudeModel::Claude3Haiku20240307.max_tokens(),
-            4096
+            Some(4096)
+        );
+        assert_eq!(
+            ClaudeModel::new("claude-next").max_tokens(),
+            None
         );
     }
 
@@ -120,6 +275,10 @@ mod tests {
                 .unwrap(),
             ClaudeModel::Claude3Haiku20240307
         );
+        assert_eq!(
+            serde_json::from_str::<ClaudeModel>("\"claude-next\"").unwrap(),
+            ClaudeModel::Custom("claude-next".to_string())
+        );
     }
 
     #[test]
@@ -136,5 +295,12 @@ mod tests {
             serde_json::to_string(&ClaudeModel::Claude3Haiku20240307).unwrap(),
             "\"claude-3-haiku-20240307\""
         );
+        assert_eq!(
+            serde_json::to_string(&ClaudeModel::Custom(
+                "claude-next".to_string()
+            ))
+            .unwrap(),
+            "\"claude-next\""
+        );
     }
 }
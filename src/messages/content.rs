@@ -3,6 +3,8 @@ use crate::macros::{
     impl_enum_struct_serialization,
     impl_enum_with_string_or_array_serialization,
 };
+use crate::messages::{media, CacheControl, Citation, ContentSourceError};
+use crate::ValidationResult;
 use std::fmt::Display;
 
 /// The content of the message.
@@ -34,6 +36,27 @@ impl_enum_with_string_or_array_serialization!(
 
 impl_display_for_serialize!(Content);
 
+impl Content {
+    /// Returns whether the content includes an image, including the content of tool results.
+    pub(crate) fn has_image(&self) -> bool {
+        match self {
+            | Content::SingleText(_) => false,
+            | Content::MultipleBlock(blocks) => {
+                blocks
+                    .iter()
+                    .any(|block| match block {
+                        | ContentBlock::Image(_) => true,
+                        | ContentBlock::ToolResult(tool_result) => tool_result
+                            .content
+                            .as_ref()
+                            .map_or(false, Content::has_image),
+                        | _ => false,
+                    })
+            },
+        }
+    }
+}
+
 /// The content block of the message.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ContentBlock {
@@ -41,8 +64,26 @@ pub enum ContentBlock {
     Text(TextContentBlock),
     /// The image content block.
     Image(ImageContentBlock),
+    /// The document content block.
+    Document(DocumentContentBlock),
     /// The text delta content block.
     TextDelta(TextDeltaContentBlock),
+    /// The tool use content block.
+    ToolUse(ToolUseContentBlock),
+    /// The tool result content block.
+    ToolResult(ToolResultContentBlock),
+    /// The input JSON delta content block.
+    InputJsonDelta(InputJsonDeltaContentBlock),
+    /// The citations delta content block.
+    CitationsDelta(CitationsDeltaContentBlock),
+    /// The thinking content block.
+    Thinking(ThinkingContentBlock),
+    /// The redacted thinking content block.
+    RedactedThinking(RedactedThinkingContentBlock),
+    /// The thinking delta content block.
+    ThinkingDelta(ThinkingDeltaContentBlock),
+    /// The signature delta content block.
+    SignatureDelta(SignatureDeltaContentBlock),
 }
 
 impl Default for ContentBlock {
@@ -56,7 +97,19 @@ impl_enum_struct_serialization!(
     type,
     Text(TextContentBlock, "text"),
     Image(ImageContentBlock, "image"),
-    TextDelta(TextDeltaContentBlock, "text_delta")
+    Document(DocumentContentBlock, "document"),
+    TextDelta(TextDeltaContentBlock, "text_delta"),
+    ToolUse(ToolUseContentBlock, "tool_use"),
+    ToolResult(ToolResultContentBlock, "tool_result"),
+    InputJsonDelta(InputJsonDeltaContentBlock, "input_json_delta"),
+    CitationsDelta(CitationsDeltaContentBlock, "citations_delta"),
+    Thinking(ThinkingContentBlock, "thinking"),
+    RedactedThinking(
+        RedactedThinkingContentBlock,
+        "redacted_thinking"
+    ),
+    ThinkingDelta(ThinkingDeltaContentBlock, "thinking_delta"),
+    SignatureDelta(SignatureDeltaContentBlock, "signature_delta")
 );
 
 impl_display_for_serialize!(ContentBlock);
@@ -69,6 +122,12 @@ pub struct TextContentBlock {
     pub _type: ContentType,
     /// The text content.
     pub text: String,
+    /// The cache control to mark a prompt caching breakpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+    /// The citations of the documents supporting the text in the response.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub citations: Option<Vec<Citation>>,
 }
 
 impl Default for TextContentBlock {
@@ -76,6 +135,8 @@ impl Default for TextContentBlock {
         Self {
             _type: ContentType::Text,
             text: String::new(),
+            cache_control: None,
+            citations: None,
         }
     }
 }
@@ -87,6 +148,8 @@ impl From<String> for TextContentBlock {
         Self {
             _type: ContentType::Text,
             text,
+            cache_control: None,
+            citations: None,
         }
     }
 }
@@ -96,6 +159,8 @@ impl From<&str> for TextContentBlock {
         Self {
             _type: ContentType::Text,
             text: text.to_string(),
+            cache_control: None,
+            citations: None,
         }
     }
 }
@@ -109,8 +174,22 @@ impl TextContentBlock {
         Self {
             _type: ContentType::Text,
             text: text.into(),
+            cache_control: None,
+            citations: None,
         }
     }
+
+    /// Sets the cache control to mark a prompt caching breakpoint at this block.
+    ///
+    /// ## Arguments
+    /// - `cache_control` - The cache control.
+    pub fn with_cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
 }
 
 /// The image content block.
@@ -121,6 +200,9 @@ pub struct ImageContentBlock {
     pub _type: ContentType,
     /// The image content source.
     pub source: ImageContentSource,
+    /// The cache control to mark a prompt caching breakpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
 }
 
 impl Default for ImageContentBlock {
@@ -128,6 +210,7 @@ impl Default for ImageContentBlock {
         Self {
             _type: ContentType::Image,
             source: ImageContentSource::default(),
+            cache_control: None,
         }
     }
 }
@@ -140,8 +223,114 @@ impl ImageContentBlock {
         Self {
             _type: ContentType::Image,
             source,
+            cache_control: None,
         }
     }
+
+    /// Creates a new image content block from the raw bytes of the image.
+    ///
+    /// The media type is detected from the magic bytes and the data is base64 encoded.
+    ///
+    /// ## Arguments
+    /// - `bytes` - The raw bytes of a JPEG, PNG, GIF or WebP image.
+    ///
+    /// ## Errors
+    /// It returns a validation error if the format is unknown, the base64 encoded size exceeds 5 MB or the width or height exceeds 8000 pixels.
+    pub fn from_bytes(bytes: &[u8]) -> ValidationResult<Self, String> {
+        Ok(Self::new(ImageContentSource::from_bytes(
+            bytes,
+        )?))
+    }
+
+    /// Creates a new image content block by reading the image to the end.
+    ///
+    /// ## Arguments
+    /// - `reader` - The reader of a JPEG, PNG, GIF or WebP image.
+    ///
+    /// ## Errors
+    /// It returns an error if reading fails or the image is invalid, see [`ImageContentBlock::from_bytes`].
+    pub fn from_reader<R>(mut reader: R) -> Result<Self, ContentSourceError>
+    where
+        R: std::io::Read,
+    {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        Ok(Self::from_bytes(&bytes)?)
+    }
+
+    /// Creates a new image content block from the decoded image of the `image` crate.
+    ///
+    /// The image is encoded as PNG, see [`ImageContentSource::from_dynamic_image`].
+    ///
+    /// ## Arguments
+    /// - `image` - The decoded image.
+    ///
+    /// ## Errors
+    /// It returns an error if encoding fails or the encoded image is invalid, see [`ImageContentBlock::from_bytes`].
+    ///
+    /// ## NOTE
+    /// This is available with the feature flag: `image`.
+    #[cfg(feature = "image")]
+    pub fn from_dynamic_image(
+        image: &image::DynamicImage
+    ) -> Result<Self, ContentSourceError> {
+        Ok(Self::new(
+            ImageContentSource::from_dynamic_image(image)?,
+        ))
+    }
+
+    /// Creates a new image content block from the image file.
+    ///
+    /// ## Arguments
+    /// - `path` - The path of a JPEG, PNG, GIF or WebP image file.
+    ///
+    /// ## Errors
+    /// It returns an error if reading the file fails or the image is invalid, see [`ImageContentBlock::from_bytes`].
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::messages::{ContentBlock, ImageContentBlock, Message, TextContentBlock};
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let message = Message::user(vec![
+    ///     ContentBlock::from(ImageContentBlock::from_file("image.png")?),
+    ///     ContentBlock::from(TextContentBlock::new("Describe this image.")),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_file<P>(path: P) -> Result<Self, ContentSourceError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let bytes = std::fs::read(path)?;
+
+        Ok(Self::from_bytes(&bytes)?)
+    }
+
+    /// Creates a new image content block referencing the image by the URL.
+    ///
+    /// ## Arguments
+    /// - `url` - The URL of the image.
+    pub fn from_url<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(ImageContentSource::url(url))
+    }
+
+    /// Sets the cache control to mark a prompt caching breakpoint at this block.
+    ///
+    /// ## Arguments
+    /// - `cache_control` - The cache control.
+    pub fn with_cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
 }
 
 /// The content type of the message.
@@ -151,8 +340,26 @@ pub enum ContentType {
     Text,
     /// image
     Image,
+    /// document
+    Document,
     /// text_delta
     TextDelta,
+    /// tool_use
+    ToolUse,
+    /// tool_result
+    ToolResult,
+    /// input_json_delta
+    InputJsonDelta,
+    /// citations_delta
+    CitationsDelta,
+    /// thinking
+    Thinking,
+    /// redacted_thinking
+    RedactedThinking,
+    /// thinking_delta
+    ThinkingDelta,
+    /// signature_delta
+    SignatureDelta,
 }
 
 impl Default for ContentType {
@@ -173,9 +380,36 @@ impl Display for ContentType {
             | ContentType::Image => {
                 write!(f, "image")
             },
+            | ContentType::Document => {
+                write!(f, "document")
+            },
             | ContentType::TextDelta => {
                 write!(f, "text_delta")
             },
+            | ContentType::ToolUse => {
+                write!(f, "tool_use")
+            },
+            | ContentType::ToolResult => {
+                write!(f, "tool_result")
+            },
+            | ContentType::InputJsonDelta => {
+                write!(f, "input_json_delta")
+            },
+            | ContentType::CitationsDelta => {
+                write!(f, "citations_delta")
+            },
+            | ContentType::Thinking => {
+                write!(f, "thinking")
+            },
+            | ContentType::RedactedThinking => {
+                write!(f, "redacted_thinking")
+            },
+            | ContentType::ThinkingDelta => {
+                write!(f, "thinking_delta")
+            },
+            | ContentType::SignatureDelta => {
+                write!(f, "signature_delta")
+            },
         }
     }
 }
@@ -184,38 +418,206 @@ impl_enum_string_serialization!(
     ContentType,
     Text => "text",
     Image => "image",
-    TextDelta => "text_delta"
+    Document => "document",
+    TextDelta => "text_delta",
+    ToolUse => "tool_use",
+    ToolResult => "tool_result",
+    InputJsonDelta => "input_json_delta",
+    CitationsDelta => "citations_delta",
+    Thinking => "thinking",
+    RedactedThinking => "redacted_thinking",
+    ThinkingDelta => "thinking_delta",
+    SignatureDelta => "signature_delta"
 );
 
 /// The image content source.
+///
+/// It is either the base64 encoded data with the media type or the URL of the image.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageContentSource {
+    /// The base64 encoded image source.
+    Base64(Base64ImageSource),
+    /// The URL image source.
+    Url(UrlImageSource),
+}
+
+impl Default for ImageContentSource {
+    fn default() -> Self {
+        Self::Base64(Base64ImageSource::default())
+    }
+}
+
+impl_enum_struct_serialization!(
+    ImageContentSource,
+    type,
+    Base64(Base64ImageSource, "base64"),
+    Url(UrlImageSource, "url")
+);
+
+impl_display_for_serialize!(ImageContentSource);
+
+impl ImageContentSource {
+    /// Creates a new base64 image source.
+    ///
+    /// ## Arguments
+    /// - `media_type` - The media type of the image.
+    /// - `data` - The base64 encoded data of the image.
+    pub fn base64<S>(
+        media_type: ImageMediaType,
+        data: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Base64(Base64ImageSource::new(
+            media_type, data,
+        ))
+    }
+
+    /// Creates a new URL image source.
+    ///
+    /// ## Arguments
+    /// - `url` - The URL of the image.
+    pub fn url<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Url(UrlImageSource::new(url))
+    }
+
+    /// Creates a new base64 image source from the raw bytes of the image.
+    ///
+    /// The media type is detected from the magic bytes.
+    ///
+    /// ## Arguments
+    /// - `bytes` - The raw bytes of a JPEG, PNG, GIF or WebP image.
+    ///
+    /// ## Errors
+    /// It returns a validation error if the format is unknown, the base64 encoded size exceeds 5 MB or the width or height exceeds 8000 pixels.
+    pub fn from_bytes(bytes: &[u8]) -> ValidationResult<Self, String> {
+        let media_type = media::validate_image(bytes)?;
+
+        Ok(Self::base64(
+            media_type,
+            media::encode_base64(bytes),
+        ))
+    }
+
+    /// Creates a new base64 image source from the decoded image of the `image` crate.
+    ///
+    /// The image is encoded as PNG, which keeps the pixels losslessly.
+    ///
+    /// ## Arguments
+    /// - `image` - The decoded image.
+    ///
+    /// ## Errors
+    /// It returns an error if encoding fails or the encoded image is invalid, see [`ImageContentSource::from_bytes`].
+    ///
+    /// ## NOTE
+    /// This is available with the feature flag: `image`.
+    #[cfg(feature = "image")]
+    pub fn from_dynamic_image(
+        image: &image::DynamicImage
+    ) -> Result<Self, ContentSourceError> {
+        let mut bytes = Vec::new();
+        image.write_to(
+            &mut std::io::Cursor::new(&mut bytes),
+            image::ImageOutputFormat::Png,
+        )?;
+
+        Ok(Self::from_bytes(&bytes)?)
+    }
+}
+
+/// The base64 encoded image source.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-pub struct ImageContentSource {
-    /// The source type.
+pub struct Base64ImageSource {
+    /// The source type. It is always `base64`.
     #[serde(rename = "type")]
     pub _type: ImageSourceType,
-    /// The media type.
+    /// The media type of the image.
     pub media_type: ImageMediaType,
-    ///  The data of the image.
+    /// The base64 encoded data of the image.
     pub data: String,
 }
 
-impl Default for ImageContentSource {
+impl Default for Base64ImageSource {
     fn default() -> Self {
         Self {
-            _type: ImageSourceType::default(),
+            _type: ImageSourceType::Base64,
             media_type: ImageMediaType::default(),
             data: String::new(),
         }
     }
 }
 
-impl_display_for_serialize!(ImageContentSource);
+impl_display_for_serialize!(Base64ImageSource);
+
+impl Base64ImageSource {
+    /// Creates a new base64 image source.
+    ///
+    /// ## Arguments
+    /// - `media_type` - The media type of the image.
+    /// - `data` - The base64 encoded data of the image.
+    pub fn new<S>(
+        media_type: ImageMediaType,
+        data: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ImageSourceType::Base64,
+            media_type,
+            data: data.into(),
+        }
+    }
+}
+
+/// The URL image source.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UrlImageSource {
+    /// The source type. It is always `url`.
+    #[serde(rename = "type")]
+    pub _type: ImageSourceType,
+    /// The URL of the image.
+    pub url: String,
+}
+
+impl Default for UrlImageSource {
+    fn default() -> Self {
+        Self {
+            _type: ImageSourceType::Url,
+            url: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(UrlImageSource);
+
+impl UrlImageSource {
+    /// Creates a new URL image source.
+    ///
+    /// ## Arguments
+    /// - `url` - The URL of the image.
+    pub fn new<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ImageSourceType::Url,
+            url: url.into(),
+        }
+    }
+}
 
 /// The source type of the image.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ImageSourceType {
     /// base64
     Base64,
+    /// url
+    Url,
 }
 
 impl Default for ImageSourceType {
@@ -233,13 +635,17 @@ impl Display for ImageSourceType {
             | ImageSourceType::Base64 => {
                 write!(f, "base64")
             },
+            | ImageSourceType::Url => {
+                write!(f, "url")
+            },
         }
     }
 }
 
 impl_enum_string_serialization!(
     ImageSourceType,
-    Base64 => "base64"
+    Base64 => "base64",
+    Url => "url"
 );
 
 /// The media type of the image.
@@ -283,56 +689,904 @@ impl Display for ImageMediaType {
     }
 }
 
-impl_enum_string_serialization!(
-    ImageMediaType,
-    Jpeg => "image/jpeg",
-    Png => "image/png",
-    Gif => "image/gif",
-    Webp => "image/webp"
-);
+impl_enum_string_serialization!(
+    ImageMediaType,
+    Jpeg => "image/jpeg",
+    Png => "image/png",
+    Gif => "image/gif",
+    Webp => "image/webp"
+);
+
+/// The document content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DocumentContentBlock {
+    /// The content type. It is always `document`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The document content source.
+    pub source: DocumentContentSource,
+    /// The title of the document passed to the model but not used for citations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// The context about the document passed to the model but not used for citations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// The citations configuration of the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citations: Option<CitationsConfig>,
+    /// The cache control to mark a prompt caching breakpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl Default for DocumentContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::Document,
+            source: DocumentContentSource::default(),
+            title: None,
+            context: None,
+            citations: None,
+            cache_control: None,
+        }
+    }
+}
+
+impl_display_for_serialize!(DocumentContentBlock);
+
+impl DocumentContentBlock {
+    /// Creates a new document content block.
+    pub fn new(source: DocumentContentSource) -> Self {
+        Self {
+            _type: ContentType::Document,
+            source,
+            title: None,
+            context: None,
+            citations: None,
+            cache_control: None,
+        }
+    }
+
+    /// Creates a new document content block from the raw bytes of the PDF.
+    ///
+    /// ## Arguments
+    /// - `bytes` - The raw bytes of the PDF.
+    ///
+    /// ## Errors
+    /// It returns a validation error if the bytes are not PDF, the size exceeds 32 MB or the number of pages exceeds 100.
+    /// The number of pages is read from the page tree, and is left to the API to validate if it can not be read, e.g. for an encrypted PDF.
+    pub fn from_pdf_bytes(bytes: &[u8]) -> ValidationResult<Self, String> {
+        Ok(Self::new(
+            DocumentContentSource::from_pdf_bytes(bytes)?,
+        ))
+    }
+
+    /// Creates a new document content block by reading the PDF to the end.
+    ///
+    /// ## Arguments
+    /// - `reader` - The reader of the PDF.
+    ///
+    /// ## Errors
+    /// It returns an error if reading fails or the PDF is invalid, see [`DocumentContentBlock::from_pdf_bytes`].
+    pub fn from_pdf_reader<R>(
+        mut reader: R
+    ) -> Result<Self, ContentSourceError>
+    where
+        R: std::io::Read,
+    {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        Ok(Self::from_pdf_bytes(&bytes)?)
+    }
+
+    /// Creates a new document content block from the PDF file.
+    ///
+    /// ## Arguments
+    /// - `path` - The path of the PDF file.
+    ///
+    /// ## Errors
+    /// It returns an error if reading the file fails or the PDF is invalid, see [`DocumentContentBlock::from_pdf_bytes`].
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::messages::{ContentBlock, DocumentContentBlock, Message, TextContentBlock};
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let message = Message::user(vec![
+    ///     ContentBlock::from(
+    ///         DocumentContentBlock::from_pdf_file("contract.pdf")?
+    ///             .with_title("Contract")
+    ///             .with_citations(true),
+    ///     ),
+    ///     ContentBlock::from(TextContentBlock::new("Summarize the terms.")),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_pdf_file<P>(path: P) -> Result<Self, ContentSourceError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let bytes = std::fs::read(path)?;
+
+        Ok(Self::from_pdf_bytes(&bytes)?)
+    }
+
+    /// Creates a new document content block of the plain text.
+    ///
+    /// ## Arguments
+    /// - `text` - The plain text of the document.
+    pub fn from_text<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(DocumentContentSource::text(text))
+    }
+
+    /// Creates a new document content block from the plain text file.
+    ///
+    /// ## Arguments
+    /// - `path` - The path of the UTF-8 text file.
+    ///
+    /// ## Errors
+    /// It returns an error if reading the file fails or the file is not valid UTF-8.
+    pub fn from_text_file<P>(path: P) -> Result<Self, ContentSourceError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        Ok(Self::from_text(std::fs::read_to_string(
+            path,
+        )?))
+    }
+
+    /// Sets the title of the document.
+    ///
+    /// ## Arguments
+    /// - `title` - The title of the document.
+    pub fn with_title<S>(
+        mut self,
+        title: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the context about the document, e.g. the metadata.
+    ///
+    /// ## Arguments
+    /// - `context` - The context about the document.
+    pub fn with_context<S>(
+        mut self,
+        context: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.context = Some(context.into());
+        self
+    }
+
+    /// Sets whether the model cites the document in the response.
+    ///
+    /// ## Arguments
+    /// - `enabled` - Whether citations are enabled.
+    pub fn with_citations(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.citations = Some(CitationsConfig::new(enabled));
+        self
+    }
+
+    /// Sets the cache control to mark a prompt caching breakpoint at this block.
+    ///
+    /// ## Arguments
+    /// - `cache_control` - The cache control.
+    pub fn with_cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
+}
+
+/// The document content source.
+///
+/// It is either the base64 encoded PDF or the plain text of the document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DocumentContentSource {
+    /// The base64 encoded PDF source.
+    Base64Pdf(Base64PdfSource),
+    /// The plain text source.
+    PlainText(PlainTextSource),
+}
+
+impl Default for DocumentContentSource {
+    fn default() -> Self {
+        Self::Base64Pdf(Base64PdfSource::default())
+    }
+}
+
+impl_enum_struct_serialization!(
+    DocumentContentSource,
+    type,
+    Base64Pdf(Base64PdfSource, "base64"),
+    PlainText(PlainTextSource, "text")
+);
+
+impl_display_for_serialize!(DocumentContentSource);
+
+impl DocumentContentSource {
+    /// Creates a new base64 PDF source.
+    ///
+    /// ## Arguments
+    /// - `data` - The base64 encoded data of the PDF.
+    pub fn pdf<S>(data: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Base64Pdf(Base64PdfSource::new(data))
+    }
+
+    /// Creates a new plain text source.
+    ///
+    /// ## Arguments
+    /// - `text` - The plain text of the document.
+    pub fn text<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::PlainText(PlainTextSource::new(text))
+    }
+
+    /// Creates a new base64 PDF source from the raw bytes of the PDF.
+    ///
+    /// ## Arguments
+    /// - `bytes` - The raw bytes of the PDF.
+    ///
+    /// ## Errors
+    /// It returns a validation error if the bytes are not PDF, the size exceeds 32 MB or the number of pages exceeds 100.
+    /// The number of pages is read from the page tree, and is left to the API to validate if it can not be read, e.g. for an encrypted PDF.
+    pub fn from_pdf_bytes(bytes: &[u8]) -> ValidationResult<Self, String> {
+        media::validate_pdf(bytes)?;
+
+        Ok(Self::pdf(media::encode_base64(bytes)))
+    }
+}
+
+/// The base64 encoded PDF source.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Base64PdfSource {
+    /// The source type. It is always `base64`.
+    #[serde(rename = "type")]
+    pub _type: DocumentSourceType,
+    /// The media type. It is always `application/pdf`.
+    pub media_type: DocumentMediaType,
+    /// The base64 encoded data of the PDF.
+    pub data: String,
+}
+
+impl Default for Base64PdfSource {
+    fn default() -> Self {
+        Self {
+            _type: DocumentSourceType::Base64,
+            media_type: DocumentMediaType::Pdf,
+            data: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(Base64PdfSource);
+
+impl Base64PdfSource {
+    /// Creates a new base64 PDF source.
+    ///
+    /// ## Arguments
+    /// - `data` - The base64 encoded data of the PDF.
+    pub fn new<S>(data: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: DocumentSourceType::Base64,
+            media_type: DocumentMediaType::Pdf,
+            data: data.into(),
+        }
+    }
+}
+
+/// The plain text source.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PlainTextSource {
+    /// The source type. It is always `text`.
+    #[serde(rename = "type")]
+    pub _type: DocumentSourceType,
+    /// The media type. It is always `text/plain`.
+    pub media_type: DocumentMediaType,
+    /// The plain text of the document.
+    pub data: String,
+}
+
+impl Default for PlainTextSource {
+    fn default() -> Self {
+        Self {
+            _type: DocumentSourceType::Text,
+            media_type: DocumentMediaType::PlainText,
+            data: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(PlainTextSource);
+
+impl PlainTextSource {
+    /// Creates a new plain text source.
+    ///
+    /// ## Arguments
+    /// - `text` - The plain text of the document.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: DocumentSourceType::Text,
+            media_type: DocumentMediaType::PlainText,
+            data: text.into(),
+        }
+    }
+}
+
+/// The source type of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentSourceType {
+    /// base64
+    Base64,
+    /// text
+    Text,
+}
+
+impl Default for DocumentSourceType {
+    fn default() -> Self {
+        Self::Base64
+    }
+}
+
+impl Display for DocumentSourceType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | DocumentSourceType::Base64 => {
+                write!(f, "base64")
+            },
+            | DocumentSourceType::Text => {
+                write!(f, "text")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    DocumentSourceType,
+    Base64 => "base64",
+    Text => "text"
+);
+
+/// The media type of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentMediaType {
+    /// application/pdf
+    Pdf,
+    /// text/plain
+    PlainText,
+}
+
+impl Default for DocumentMediaType {
+    fn default() -> Self {
+        Self::Pdf
+    }
+}
+
+impl Display for DocumentMediaType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | DocumentMediaType::Pdf => {
+                write!(f, "application/pdf")
+            },
+            | DocumentMediaType::PlainText => {
+                write!(f, "text/plain")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    DocumentMediaType,
+    Pdf => "application/pdf",
+    PlainText => "text/plain"
+);
+
+/// The citations configuration of a document.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct CitationsConfig {
+    /// Whether citations are enabled.
+    pub enabled: bool,
+}
+
+impl_display_for_serialize!(CitationsConfig);
+
+impl CitationsConfig {
+    /// Creates a new citations configuration.
+    ///
+    /// ## Arguments
+    /// - `enabled` - Whether citations are enabled.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+        }
+    }
+}
+
+/// The text delta content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TextDeltaContentBlock {
+    /// The content type. It is always `text_delta`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The text delta content.
+    pub text: String,
+}
+
+impl Default for TextDeltaContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::TextDelta,
+            text: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(TextDeltaContentBlock);
+
+impl From<String> for TextDeltaContentBlock {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for TextDeltaContentBlock {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl TextDeltaContentBlock {
+    /// Creates a new text content block.
+    pub(crate) fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::TextDelta,
+            text: text.into(),
+        }
+    }
+}
+
+/// The tool use content block.
+///
+/// It is generated by the model when it decides to use a tool.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ToolUseContentBlock {
+    /// The content type. It is always `tool_use`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The unique identifier of this tool use.
+    pub id: String,
+    /// The name of the tool to use.
+    pub name: String,
+    /// The input of the tool, which conforms to the `input_schema` of the tool definition.
+    pub input: serde_json::Value,
+    /// The cache control to mark a prompt caching breakpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl Default for ToolUseContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::ToolUse,
+            id: String::new(),
+            name: String::new(),
+            input: serde_json::Value::Object(serde_json::Map::new()),
+            cache_control: None,
+        }
+    }
+}
+
+impl_display_for_serialize!(ToolUseContentBlock);
+
+impl ToolUseContentBlock {
+    /// Creates a new tool use content block.
+    ///
+    /// ## Arguments
+    /// - `id` - The unique identifier of this tool use.
+    /// - `name` - The name of the tool to use.
+    /// - `input` - The input of the tool.
+    pub fn new<S, T>(
+        id: S,
+        name: T,
+        input: serde_json::Value,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            _type: ContentType::ToolUse,
+            id: id.into(),
+            name: name.into(),
+            input,
+            cache_control: None,
+        }
+    }
+
+    /// Sets the cache control to mark a prompt caching breakpoint at this block.
+    ///
+    /// ## Arguments
+    /// - `cache_control` - The cache control.
+    pub fn with_cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
+}
+
+/// The tool result content block.
+///
+/// It is sent by the user to return the result of a tool use to the model.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ToolResultContentBlock {
+    /// The content type. It is always `tool_result`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The identifier of the tool use that this result is for.
+    pub tool_use_id: String,
+    /// The result of the tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+    /// Whether the tool use resulted in an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_error: Option<bool>,
+    /// The cache control to mark a prompt caching breakpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl Default for ToolResultContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::ToolResult,
+            tool_use_id: String::new(),
+            content: None,
+            is_error: None,
+            cache_control: None,
+        }
+    }
+}
+
+impl_display_for_serialize!(ToolResultContentBlock);
+
+impl ToolResultContentBlock {
+    /// Creates a new tool result content block.
+    ///
+    /// ## Arguments
+    /// - `tool_use_id` - The identifier of the tool use that this result is for.
+    /// - `content` - The result of the tool use.
+    /// - `is_error` - Whether the tool use resulted in an error.
+    pub fn new<S>(
+        tool_use_id: S,
+        content: Option<Content>,
+        is_error: Option<bool>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::ToolResult,
+            tool_use_id: tool_use_id.into(),
+            content,
+            is_error,
+            cache_control: None,
+        }
+    }
+
+    /// Creates a new successful tool result content block.
+    ///
+    /// ## Arguments
+    /// - `tool_use_id` - The identifier of the tool use that this result is for.
+    /// - `content` - The result of the tool use.
+    pub fn success<S, T>(
+        tool_use_id: S,
+        content: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<Content>,
+    {
+        Self::new(tool_use_id, Some(content.into()), None)
+    }
+
+    /// Creates a new failed tool result content block.
+    ///
+    /// ## Arguments
+    /// - `tool_use_id` - The identifier of the tool use that this result is for.
+    /// - `content` - The error message of the tool use.
+    pub fn error<S, T>(
+        tool_use_id: S,
+        content: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<Content>,
+    {
+        Self::new(
+            tool_use_id,
+            Some(content.into()),
+            Some(true),
+        )
+    }
+
+    /// Sets the cache control to mark a prompt caching breakpoint at this block.
+    ///
+    /// ## Arguments
+    /// - `cache_control` - The cache control.
+    pub fn with_cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
+}
+
+/// The input JSON delta content block.
+///
+/// It is streamed as a fragment of the input of a tool use content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct InputJsonDeltaContentBlock {
+    /// The content type. It is always `input_json_delta`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The partial JSON string of the tool input.
+    pub partial_json: String,
+}
+
+impl Default for InputJsonDeltaContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::InputJsonDelta,
+            partial_json: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(InputJsonDeltaContentBlock);
+
+impl InputJsonDeltaContentBlock {
+    /// Creates a new input JSON delta content block.
+    pub fn new<S>(partial_json: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::InputJsonDelta,
+            partial_json: partial_json.into(),
+        }
+    }
+}
+
+/// The citations delta content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CitationsDeltaContentBlock {
+    /// The content type. It is always `citations_delta`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The citation to append to the text content block.
+    pub citation: Citation,
+}
+
+impl Default for CitationsDeltaContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::CitationsDelta,
+            citation: Citation::default(),
+        }
+    }
+}
+
+impl_display_for_serialize!(CitationsDeltaContentBlock);
+
+impl From<Citation> for CitationsDeltaContentBlock {
+    fn from(citation: Citation) -> Self {
+        Self::new(citation)
+    }
+}
+
+impl CitationsDeltaContentBlock {
+    /// Creates a new citations delta content block.
+    pub fn new(citation: Citation) -> Self {
+        Self {
+            _type: ContentType::CitationsDelta,
+            citation,
+        }
+    }
+}
+
+/// The thinking content block of the internal reasoning by extended thinking.
+///
+/// Pass it back unmodified with the signature when replaying the assistant turn, e.g. for tool use.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ThinkingContentBlock {
+    /// The content type. It is always `thinking`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The thinking content.
+    pub thinking: String,
+    /// The signature to verify the thinking content was generated by the model.
+    pub signature: String,
+}
+
+impl Default for ThinkingContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::Thinking,
+            thinking: String::new(),
+            signature: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(ThinkingContentBlock);
+
+impl ThinkingContentBlock {
+    /// Creates a new thinking content block.
+    ///
+    /// ## Arguments
+    /// - `thinking` - The thinking content.
+    /// - `signature` - The signature of the thinking content.
+    pub fn new<S, T>(
+        thinking: S,
+        signature: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            _type: ContentType::Thinking,
+            thinking: thinking.into(),
+            signature: signature.into(),
+        }
+    }
+}
+
+/// The redacted thinking content block whose content is encrypted for safety reasons.
+///
+/// Pass it back unmodified when replaying the assistant turn.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RedactedThinkingContentBlock {
+    /// The content type. It is always `redacted_thinking`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The encrypted thinking content.
+    pub data: String,
+}
+
+impl Default for RedactedThinkingContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::RedactedThinking,
+            data: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(RedactedThinkingContentBlock);
 
-/// The text delta content block.
+impl RedactedThinkingContentBlock {
+    /// Creates a new redacted thinking content block.
+    ///
+    /// ## Arguments
+    /// - `data` - The encrypted thinking content.
+    pub fn new<S>(data: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::RedactedThinking,
+            data: data.into(),
+        }
+    }
+}
+
+/// The thinking delta content block.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-pub struct TextDeltaContentBlock {
-    /// The content type. It is always `text_delta`.
+pub struct ThinkingDeltaContentBlock {
+    /// The content type. It is always `thinking_delta`.
     #[serde(rename = "type")]
     pub _type: ContentType,
-    /// The text delta content.
-    pub text: String,
+    /// The thinking delta content.
+    pub thinking: String,
 }
 
-impl Default for TextDeltaContentBlock {
+impl Default for ThinkingDeltaContentBlock {
     fn default() -> Self {
         Self {
-            _type: ContentType::TextDelta,
-            text: String::new(),
+            _type: ContentType::ThinkingDelta,
+            thinking: String::new(),
         }
     }
 }
 
-impl_display_for_serialize!(TextDeltaContentBlock);
+impl_display_for_serialize!(ThinkingDeltaContentBlock);
 
-impl From<String> for TextDeltaContentBlock {
-    fn from(text: String) -> Self {
-        Self::new(text)
+impl ThinkingDeltaContentBlock {
+    /// Creates a new thinking delta content block.
+    pub fn new<S>(thinking: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::ThinkingDelta,
+            thinking: thinking.into(),
+        }
     }
 }
 
-impl From<&str> for TextDeltaContentBlock {
-    fn from(text: &str) -> Self {
-        Self::new(text)
+/// The signature delta content block sent just before the thinking content block stops.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SignatureDeltaContentBlock {
+    /// The content type. It is always `signature_delta`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The signature delta of the thinking content.
+    pub signature: String,
+}
+
+impl Default for SignatureDeltaContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::SignatureDelta,
+            signature: String::new(),
+        }
     }
 }
 
-impl TextDeltaContentBlock {
-    /// Creates a new text content block.
-    pub(crate) fn new<S>(text: S) -> Self
+impl_display_for_serialize!(SignatureDeltaContentBlock);
+
+impl SignatureDeltaContentBlock {
+    /// Creates a new signature delta content block.
+    pub fn new<S>(signature: S) -> Self
     where
         S: Into<String>,
     {
         Self {
-            _type: ContentType::TextDelta,
-            text: text.into(),
+            _type: ContentType::SignatureDelta,
+            signature: signature.into(),
         }
     }
 }
@@ -365,6 +1619,42 @@ mod tests {
             ContentType::TextDelta.to_string(),
             "text_delta"
         );
+        assert_eq!(
+            ContentType::ToolUse.to_string(),
+            "tool_use"
+        );
+        assert_eq!(
+            ContentType::ToolResult.to_string(),
+            "tool_result"
+        );
+        assert_eq!(
+            ContentType::InputJsonDelta.to_string(),
+            "input_json_delta"
+        );
+        assert_eq!(
+            ContentType::Document.to_string(),
+            "document"
+        );
+        assert_eq!(
+            ContentType::CitationsDelta.to_string(),
+            "citations_delta"
+        );
+        assert_eq!(
+            ContentType::Thinking.to_string(),
+            "thinking"
+        );
+        assert_eq!(
+            ContentType::RedactedThinking.to_string(),
+            "redacted_thinking"
+        );
+        assert_eq!(
+            ContentType::ThinkingDelta.to_string(),
+            "thinking_delta"
+        );
+        assert_eq!(
+            ContentType::SignatureDelta.to_string(),
+            "signature_delta"
+        );
     }
 
     #[test]
@@ -381,6 +1671,14 @@ mod tests {
             serde_json::to_string(&ContentType::TextDelta).unwrap(),
             "\"text_delta\""
         );
+        assert_eq!(
+            serde_json::to_string(&ContentType::ToolUse).unwrap(),
+            "\"tool_use\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ContentType::ToolResult).unwrap(),
+            "\"tool_result\""
+        );
     }
 
     #[test]
@@ -397,6 +1695,14 @@ mod tests {
             serde_json::from_str::<ContentType>("\"text_delta\"").unwrap(),
             ContentType::TextDelta
         );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"tool_use\"").unwrap(),
+            ContentType::ToolUse
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"tool_result\"").unwrap(),
+            ContentType::ToolResult
+        );
     }
 
     #[test]
@@ -503,21 +1809,18 @@ mod tests {
     fn default_image_content_source() {
         assert_eq!(
             ImageContentSource::default(),
-            ImageContentSource {
+            ImageContentSource::Base64(Base64ImageSource {
                 _type: ImageSourceType::Base64,
                 media_type: ImageMediaType::Jpeg,
                 data: String::new(),
-            }
+            })
         );
     }
 
     #[test]
     fn display_image_content_source() {
-        let image_content_source = ImageContentSource {
-            _type: ImageSourceType::Base64,
-            media_type: ImageMediaType::Jpeg,
-            data: "data".to_string(),
-        };
+        let image_content_source =
+            ImageContentSource::base64(ImageMediaType::Jpeg, "data");
         assert_eq!(
             image_content_source.to_string(),
             "{\n  \"type\": \"base64\",\n  \"media_type\": \"image/jpeg\",\n  \"data\": \"data\"\n}"
@@ -526,28 +1829,149 @@ mod tests {
 
     #[test]
     fn serialize_image_content_source() {
-        let image_content_source = ImageContentSource {
-            _type: ImageSourceType::Base64,
-            media_type: ImageMediaType::Jpeg,
-            data: "data".to_string(),
-        };
+        let image_content_source =
+            ImageContentSource::base64(ImageMediaType::Jpeg, "data");
         assert_eq!(
             serde_json::to_string(&image_content_source).unwrap(),
             "{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"data\"}"
         );
     }
 
+    #[test]
+    fn serialize_url_image_content_source() {
+        let image_content_source =
+            ImageContentSource::url("https://example.com/image.png");
+        assert_eq!(
+            serde_json::to_string(&image_content_source).unwrap(),
+            "{\"type\":\"url\",\"url\":\"https://example.com/image.png\"}"
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageContentSource>(
+                "{\"type\":\"url\",\"url\":\"https://example.com/image.png\"}"
+            )
+            .unwrap(),
+            image_content_source
+        );
+    }
+
+    #[test]
+    fn image_content_block_from_bytes() {
+        let bytes = crate::messages::media::tests::png_header(16, 16);
+        let image_content_block =
+            ImageContentBlock::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            image_content_block.source,
+            ImageContentSource::Base64(Base64ImageSource::new(
+                ImageMediaType::Png,
+                media::encode_base64(&bytes),
+            ))
+        );
+
+        assert!(ImageContentBlock::from_bytes(b"not an image").is_err());
+        assert!(matches!(
+            ImageContentBlock::from_reader(&b"not an image"[..]),
+            Err(ContentSourceError::ValidationError(_))
+        ));
+        assert!(matches!(
+            ImageContentBlock::from_file("not-found.png"),
+            Err(ContentSourceError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn serialize_document_content_block() {
+        let document_content_block =
+            DocumentContentBlock::from_text("The grass is green.")
+                .with_title("Facts")
+                .with_context("Trusted source")
+                .with_citations(true);
+        assert_eq!(
+            serde_json::to_string(&document_content_block).unwrap(),
+            "{\"type\":\"document\",\"source\":{\"type\":\"text\",\"media_type\":\"text/plain\",\"data\":\"The grass is green.\"},\"title\":\"Facts\",\"context\":\"Trusted source\",\"citations\":{\"enabled\":true}}"
+        );
+
+        let content_block = ContentBlock::from(DocumentContentBlock::new(
+            DocumentContentSource::pdf("data"),
+        ));
+        assert_eq!(
+            serde_json::to_string(&content_block).unwrap(),
+            "{\"type\":\"document\",\"source\":{\"type\":\"base64\",\"media_type\":\"application/pdf\",\"data\":\"data\"}}"
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"document\",\"source\":{\"type\":\"base64\",\"media_type\":\"application/pdf\",\"data\":\"data\"}}"
+            )
+            .unwrap(),
+            content_block
+        );
+
+        assert_eq!(
+            serde_json::from_str::<DocumentContentSource>(
+                "{\"type\":\"text\",\"media_type\":\"text/plain\",\"data\":\"text\"}"
+            )
+            .unwrap(),
+            DocumentContentSource::PlainText(PlainTextSource::new("text"))
+        );
+    }
+
+    #[test]
+    fn document_content_block_from_pdf_bytes() {
+        let bytes = crate::messages::media::tests::pdf(2);
+        let document_content_block =
+            DocumentContentBlock::from_pdf_bytes(&bytes).unwrap();
+        assert_eq!(
+            document_content_block.source,
+            DocumentContentSource::pdf(media::encode_base64(&bytes))
+        );
+
+        assert!(DocumentContentBlock::from_pdf_bytes(b"not a pdf").is_err());
+        assert!(matches!(
+            DocumentContentBlock::from_pdf_reader(&b"not a pdf"[..]),
+            Err(ContentSourceError::ValidationError(_))
+        ));
+        assert!(matches!(
+            DocumentContentBlock::from_pdf_file("not-found.pdf"),
+            Err(ContentSourceError::Io(_))
+        ));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn image_content_block_from_dynamic_image() {
+        let image = image::DynamicImage::new_rgb8(16, 8);
+        let image_content_block =
+            ImageContentBlock::from_dynamic_image(&image).unwrap();
+        match image_content_block.source {
+            | ImageContentSource::Base64(source) => {
+                assert_eq!(source.media_type, ImageMediaType::Png);
+            },
+            | ImageContentSource::Url(_) => panic!("unexpected URL source"),
+        }
+
+        let image = image::DynamicImage::new_rgb8(8001, 1);
+        assert!(matches!(
+            ImageContentBlock::from_dynamic_image(&image),
+            Err(ContentSourceError::ValidationError(_))
+        ));
+    }
+
     #[test]
     fn deserialize_image_content_source() {
-        let image_content_source = ImageContentSource {
-            _type: ImageSourceType::Base64,
-            media_type: ImageMediaType::Jpeg,
-            data: "data".to_string(),
-        };
+        let image_content_source =
+            ImageContentSource::base64(ImageMediaType::Jpeg, "data");
         assert_eq!(
             serde_json::from_str::<ImageContentSource>("{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"data\"}").unwrap(),
             image_content_source
         );
+
+        assert!(serde_json::from_str::<ImageContentSource>(
+            "{\"type\":\"base64\",\"media_type\":\"image/jpeg\"}"
+        )
+        .is_err());
+        assert!(serde_json::from_str::<ImageContentSource>(
+            "{\"type\":\"url\"}"
+        )
+        .is_err());
     }
 
     #[test]
@@ -558,6 +1982,8 @@ mod tests {
             TextContentBlock {
                 _type: ContentType::Text,
                 text: "text".to_string(),
+                cache_control: None,
+                citations: None,
             }
         );
     }
@@ -569,6 +1995,8 @@ mod tests {
             TextContentBlock {
                 _type: ContentType::Text,
                 text: String::new(),
+                cache_control: None,
+                citations: None,
             }
         );
     }
@@ -612,6 +2040,7 @@ mod tests {
             ImageContentBlock {
                 _type: ContentType::Image,
                 source: ImageContentSource::default(),
+                cache_control: None,
             }
         );
     }
@@ -623,6 +2052,7 @@ mod tests {
             ImageContentBlock {
                 _type: ContentType::Image,
                 source: ImageContentSource::default(),
+                cache_control: None,
             }
         );
     }
@@ -647,6 +2077,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_content_block_with_cache_control() {
+        let text_content_block = TextContentBlock::new("text")
+            .with_cache_control(CacheControl::ephemeral());
+        assert_eq!(
+            serde_json::to_string(&text_content_block).unwrap(),
+            "{\"type\":\"text\",\"text\":\"text\",\"cache_control\":{\"type\":\"ephemeral\"}}"
+        );
+
+        let image_content_block =
+            ImageContentBlock::new(ImageContentSource::default())
+                .with_cache_control(CacheControl::ephemeral());
+        assert_eq!(
+            serde_json::to_string(&image_content_block).unwrap(),
+            "{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"},\"cache_control\":{\"type\":\"ephemeral\"}}"
+        );
+    }
+
     #[test]
     fn deserialize_image_content_block() {
         let image_content_block =
@@ -717,6 +2165,226 @@ mod tests {
         );
     }
 
+    #[test]
+    fn new_tool_use_content_block() {
+        let tool_use_content_block = ToolUseContentBlock::new(
+            "toolu_01",
+            "get_weather",
+            serde_json::json!({"location": "Tokyo"}),
+        );
+        assert_eq!(
+            tool_use_content_block,
+            ToolUseContentBlock {
+                _type: ContentType::ToolUse,
+                id: "toolu_01".to_string(),
+                name: "get_weather".to_string(),
+                input: serde_json::json!({"location": "Tokyo"}),
+                cache_control: None,
+            }
+        );
+    }
+
+    #[test]
+    fn serialize_tool_use_content_block() {
+        let tool_use_content_block = ToolUseContentBlock::new(
+            "toolu_01",
+            "get_weather",
+            serde_json::json!({"location": "Tokyo"}),
+        );
+        assert_eq!(
+            serde_json::to_string(&tool_use_content_block).unwrap(),
+            "{\"type\":\"tool_use\",\"id\":\"toolu_01\",\"name\":\"get_weather\",\"input\":{\"location\":\"Tokyo\"}}"
+        );
+    }
+
+    #[test]
+    fn deserialize_tool_use_content_block() {
+        let tool_use_content_block = ToolUseContentBlock::new(
+            "toolu_01",
+            "get_weather",
+            serde_json::json!({"location": "Tokyo"}),
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"tool_use\",\"id\":\"toolu_01\",\"name\":\"get_weather\",\"input\":{\"location\":\"Tokyo\"}}"
+            )
+            .unwrap(),
+            ContentBlock::ToolUse(tool_use_content_block)
+        );
+    }
+
+    #[test]
+    fn new_tool_result_content_block() {
+        assert_eq!(
+            ToolResultContentBlock::success("toolu_01", "15 degrees"),
+            ToolResultContentBlock {
+                _type: ContentType::ToolResult,
+                tool_use_id: "toolu_01".to_string(),
+                content: Some(Content::SingleText(
+                    "15 degrees".to_string()
+                )),
+                is_error: None,
+                cache_control: None,
+            }
+        );
+        assert_eq!(
+            ToolResultContentBlock::error("toolu_01", "not found"),
+            ToolResultContentBlock {
+                _type: ContentType::ToolResult,
+                tool_use_id: "toolu_01".to_string(),
+                content: Some(Content::SingleText(
+                    "not found".to_string()
+                )),
+                is_error: Some(true),
+                cache_control: None,
+            }
+        );
+    }
+
+    #[test]
+    fn serialize_tool_result_content_block() {
+        assert_eq!(
+            serde_json::to_string(&ToolResultContentBlock::success(
+                "toolu_01",
+                "15 degrees"
+            ))
+            .unwrap(),
+            "{\"type\":\"tool_result\",\"tool_use_id\":\"toolu_01\",\"content\":\"15 degrees\"}"
+        );
+        assert_eq!(
+            serde_json::to_string(&ToolResultContentBlock::error(
+                "toolu_01",
+                "not found"
+            ))
+            .unwrap(),
+            "{\"type\":\"tool_result\",\"tool_use_id\":\"toolu_01\",\"content\":\"not found\",\"is_error\":true}"
+        );
+    }
+
+    #[test]
+    fn deserialize_tool_result_content_block() {
+        assert_eq!(
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"tool_result\",\"tool_use_id\":\"toolu_01\",\"content\":[{\"type\":\"text\",\"text\":\"15 degrees\"}]}"
+            )
+            .unwrap(),
+            ContentBlock::ToolResult(ToolResultContentBlock::success(
+                "toolu_01",
+                vec![ContentBlock::Text(TextContentBlock::new(
+                    "15 degrees"
+                ))]
+            ))
+        );
+    }
+
+    #[test]
+    fn serialize_input_json_delta_content_block() {
+        let input_json_delta_content_block =
+            InputJsonDeltaContentBlock::new("{\"location\":");
+        assert_eq!(
+            serde_json::to_string(&input_json_delta_content_block).unwrap(),
+            "{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"location\\\":\"}"
+        );
+    }
+
+    #[test]
+    fn deserialize_input_json_delta_content_block() {
+        let input_json_delta_content_block =
+            InputJsonDeltaContentBlock::new("{\"location\":");
+        assert_eq!(
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"location\\\":\"}"
+            )
+            .unwrap(),
+            ContentBlock::InputJsonDelta(input_json_delta_content_block)
+        );
+    }
+
+    #[test]
+    fn deserialize_text_content_block_with_citations() {
+        let text_content_block = serde_json::from_str::<TextContentBlock>(
+            "{\"type\":\"text\",\"text\":\"The grass is green.\",\"citations\":[{\"type\":\"char_location\",\"cited_text\":\"The grass is green.\",\"document_index\":0,\"document_title\":\"Facts\",\"start_char_index\":0,\"end_char_index\":20}]}"
+        )
+        .unwrap();
+        let citations = text_content_block
+            .citations
+            .unwrap();
+        assert_eq!(citations.len(), 1);
+        assert_eq!(
+            citations[0].cited_text(),
+            "The grass is green."
+        );
+
+        assert_eq!(
+            serde_json::from_str::<TextContentBlock>(
+                "{\"type\":\"text\",\"text\":\"text\"}"
+            )
+            .unwrap()
+            .citations,
+            None
+        );
+    }
+
+    #[test]
+    fn deserialize_citations_delta_content_block() {
+        assert_eq!(
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"citations_delta\",\"citation\":{\"type\":\"page_location\",\"cited_text\":\"Terms\",\"document_index\":0,\"start_page_number\":1,\"end_page_number\":2}}"
+            )
+            .unwrap(),
+            ContentBlock::CitationsDelta(CitationsDeltaContentBlock::new(
+                Citation::PageLocation(crate::messages::PageLocationCitation {
+                    cited_text: "Terms".to_string(),
+                    start_page_number: 1,
+                    end_page_number: 2,
+                    ..Default::default()
+                })
+            ))
+        );
+    }
+
+    #[test]
+    fn serialize_thinking_content_blocks() {
+        let content_blocks = vec![
+            ContentBlock::from(ThinkingContentBlock::new(
+                "Let me think.",
+                "signature",
+            )),
+            ContentBlock::from(RedactedThinkingContentBlock::new("encrypted")),
+        ];
+        let json = "[{\"type\":\"thinking\",\"thinking\":\"Let me think.\",\"signature\":\"signature\"},{\"type\":\"redacted_thinking\",\"data\":\"encrypted\"}]";
+        assert_eq!(
+            serde_json::to_string(&content_blocks).unwrap(),
+            json
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<ContentBlock>>(json).unwrap(),
+            content_blocks
+        );
+    }
+
+    #[test]
+    fn deserialize_thinking_delta_content_blocks() {
+        assert_eq!(
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"thinking_delta\",\"thinking\":\"Let me\"}"
+            )
+            .unwrap(),
+            ContentBlock::ThinkingDelta(ThinkingDeltaContentBlock::new(
+                "Let me"
+            ))
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"signature_delta\",\"signature\":\"signature\"}"
+            )
+            .unwrap(),
+            ContentBlock::SignatureDelta(SignatureDeltaContentBlock::new(
+                "signature"
+            ))
+        );
+    }
+
     #[test]
     fn new_content_block() {
         let content_block = ContentBlock::Text(TextContentBlock::new(
@@ -727,6 +2395,8 @@ mod tests {
             ContentBlock::Text(TextContentBlock {
                 _type: ContentType::Text,
                 text: "text".to_string(),
+                cache_control: None,
+                citations: None,
             })
         );
 
@@ -738,6 +2408,7 @@ mod tests {
             ContentBlock::Image(ImageContentBlock {
                 _type: ContentType::Image,
                 source: ImageContentSource::default(),
+                cache_control: None,
             })
         );
 
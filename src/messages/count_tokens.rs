@@ -0,0 +1,120 @@
+use crate::macros::impl_display_for_serialize;
+use crate::messages::{
+    ClaudeModel, Message, MessagesRequestBody, SystemPrompt, ThinkingConfig,
+    ToolChoice, ToolDefinition,
+};
+
+/// The request body to count the number of tokens in a message.
+///
+/// It is a subset of [`MessagesRequestBody`] without the generation parameters, e.g. `max_tokens`.
+///
+/// See also [the count message tokens API reference](https://docs.anthropic.com/en/api/messages-count-tokens).
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct CountTokensRequestBody {
+    /// The model that will complete your prompt.
+    pub model: ClaudeModel,
+    /// Input messages.
+    pub messages: Vec<Message>,
+    /// System prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<SystemPrompt>,
+    /// Definitions of tools that the model may use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ToolDefinition>>,
+    /// How the model should use the provided tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
+    /// The configuration of extended thinking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<ThinkingConfig>,
+}
+
+impl_display_for_serialize!(CountTokensRequestBody);
+
+impl From<MessagesRequestBody> for CountTokensRequestBody {
+    fn from(request_body: MessagesRequestBody) -> Self {
+        Self {
+            model: request_body.model,
+            messages: request_body.messages,
+            system: request_body.system,
+            tools: request_body.tools,
+            tool_choice: request_body.tool_choice,
+            thinking: request_body.thinking,
+        }
+    }
+}
+
+/// The response body of counting the number of tokens in a message.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct CountTokensResponseBody {
+    /// The total number of tokens across the provided list of messages, system prompt, and tools.
+    pub input_tokens: u32,
+}
+
+impl_display_for_serialize!(CountTokensResponseBody);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{MaxTokens, StreamOption, Temperature};
+
+    #[test]
+    fn from_messages_request_body() {
+        let request_body = MessagesRequestBody {
+            model: ClaudeModel::Claude3Haiku20240307,
+            messages: vec![Message::user("Hello")],
+            system: Some(SystemPrompt::new("system")),
+            max_tokens: MaxTokens::new(
+                16,
+                &ClaudeModel::Claude3Haiku20240307,
+            )
+            .unwrap(),
+            stream: Some(StreamOption::ReturnStream),
+            temperature: Some(Temperature::new(0.5).unwrap()),
+            ..Default::default()
+        };
+
+        let count_tokens_request_body =
+            CountTokensRequestBody::from(request_body);
+        assert_eq!(
+            count_tokens_request_body,
+            CountTokensRequestBody {
+                model: ClaudeModel::Claude3Haiku20240307,
+                messages: vec![Message::user("Hello")],
+                system: Some(SystemPrompt::new("system")),
+                tools: None,
+                tool_choice: None,
+                thinking: None,
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(&count_tokens_request_body).unwrap(),
+            r#"{"model":"claude-3-haiku-20240307","messages":[{"role":"user","content":"Hello"}],"system":"system"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_response_body() {
+        assert_eq!(
+            serde_json::from_str::<CountTokensResponseBody>(
+                r#"{"input_tokens":2095}"#
+            )
+            .unwrap(),
+            CountTokensResponseBody {
+                input_tokens: 2095,
+            }
+        );
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{ApiError, ClientError};
+use reqwest::StatusCode;
+
+use crate::{
+    ApiError, ApiErrorResponse, ApiErrorType, ClientError, ValidationError,
+};
 
 /// The error type for the messages API.
 #[derive(Debug, thiserror::Error)]
@@ -29,4 +33,47 @@ pub enum StreamError {
     /// Chunk data deserialization error.
     #[error(transparent)]
     ChunkDataDeserializationError(#[from] serde_json::Error),
+    /// Unexpected chunk in the sequence of the stream.
+    #[error("unexpected chunk: {0}")]
+    UnexpectedChunk(String),
+    /// API error sent by the `error` event in the stream, e.g. `overloaded_error`.
+    #[error("API error in the stream: {_type}: {response}")]
+    ApiError {
+        /// The type of the error.
+        _type: ApiErrorType,
+        /// The error event data.
+        response: ApiErrorResponse,
+    },
+    /// The stream was cancelled by the cancellation token.
+    #[error("the stream was cancelled")]
+    Cancelled,
+}
+
+impl From<ApiErrorResponse> for StreamError {
+    fn from(response: ApiErrorResponse) -> Self {
+        // The status of the stream response has been already `200 OK` when the error event arrives.
+        let _type = ApiErrorType::from_error_type(
+            &response.error._type,
+            StatusCode::OK,
+        );
+        Self::ApiError {
+            _type,
+            response,
+        }
+    }
+}
+
+/// The error type for loading the source of a content block, e.g. an image file.
+#[derive(Debug, thiserror::Error)]
+pub enum ContentSourceError {
+    /// Failed to read the source.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The source is invalid for the API.
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError<String>),
+    /// Failed to encode the image.
+    #[cfg(feature = "image")]
+    #[error(transparent)]
+    Image(#[from] image::ImageError),
 }
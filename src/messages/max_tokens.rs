@@ -49,27 +49,34 @@ impl MaxTokens {
     /// - `model` - The target Claude model.
     ///
     /// ## Errors
-    /// It returns a validation error if the value is greater than the maximum number of tokens for the model.
+    /// It returns a validation error if the value is greater than the maximum number of output tokens for the model.
+    /// The value is not validated for a model whose capabilities are unknown, see [`crate::messages::ModelRegistry`].
     pub fn new(
         value: u32,
-        model: ClaudeModel,
+        model: &ClaudeModel,
     ) -> ValidationResult<MaxTokens, u32> {
-        if value > model.max_tokens() {
-            return Err(ValidationError {
-                _type: "MaxTokens".to_string(),
-                expected: format!(
-                    "The maximum number of tokens for the model: {} is {}.",
-                    model,
-                    model.max_tokens()
-                ),
-                actual: value,
-            });
+        if let Some(max_tokens) = model.max_tokens() {
+            if value > max_tokens {
+                return Err(ValidationError {
+                    _type: "MaxTokens".to_string(),
+                    expected: format!(
+                        "The maximum number of tokens for the model: {} is {}.",
+                        model, max_tokens
+                    ),
+                    actual: value,
+                });
+            }
         }
 
         Ok(Self {
             value,
         })
     }
+
+    /// Returns the value of the maximum number of tokens.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
 }
 
 #[cfg(test)]
@@ -100,11 +107,12 @@ mod tests {
     #[test]
     fn new() {
         assert!(
-            MaxTokens::new(4096, ClaudeModel::Claude3Sonnet20240229).is_ok()
+            MaxTokens::new(4096, &ClaudeModel::Claude3Sonnet20240229).is_ok()
         );
         assert!(
-            MaxTokens::new(4097, ClaudeModel::Claude3Sonnet20240229).is_err()
+            MaxTokens::new(4097, &ClaudeModel::Claude3Sonnet20240229).is_err()
         );
+        assert!(MaxTokens::new(100000, &ClaudeModel::new("claude-next")).is_ok());
     }
 
     #[test]
@@ -0,0 +1,732 @@
+//! Helpers to build the source of a content block from raw bytes.
+
+use base64::Engine;
+
+use crate::messages::ImageMediaType;
+use crate::{ValidationError, ValidationResult};
+
+/// The maximum size of an image in bytes accepted by the API, which applies to the base64 encoded data.
+pub(crate) const MAX_IMAGE_SIZE: usize = 5 * 1024 * 1024;
+
+/// The maximum width and height of an image in pixels accepted by the API.
+pub(crate) const MAX_IMAGE_DIMENSION: u32 = 8000;
+
+/// The maximum size of a PDF document in bytes accepted by the API.
+pub(crate) const MAX_PDF_SIZE: usize = 32 * 1024 * 1024;
+
+/// The maximum number of pages of a PDF document accepted by the API.
+pub(crate) const MAX_PDF_PAGES: usize = 100;
+
+/// Encodes the bytes into the standard base64 string.
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+/// Returns the length of the standard base64 string encoded from the bytes of the length.
+pub(crate) fn base64_len(len: usize) -> usize {
+    len.div_ceil(3) * 4
+}
+
+/// Sniffs the media type of the image from the magic bytes.
+pub(crate) fn sniff_image_media_type(bytes: &[u8]) -> Option<ImageMediaType> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some(ImageMediaType::Png)
+    } else if bytes.starts_with(b"\xff\xd8\xff") {
+        Some(ImageMediaType::Jpeg)
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some(ImageMediaType::Gif)
+    } else if bytes.len() >= 12
+        && &bytes[0..4] == b"RIFF"
+        && &bytes[8..12] == b"WEBP"
+    {
+        Some(ImageMediaType::Webp)
+    } else {
+        None
+    }
+}
+
+/// Reads the width and height of the image in pixels from the header.
+pub(crate) fn image_dimensions(
+    bytes: &[u8],
+    media_type: ImageMediaType,
+) -> Option<(u32, u32)> {
+    match media_type {
+        | ImageMediaType::Png => {
+            // The IHDR chunk follows the 8 bytes signature and the chunk length and type.
+            Some((
+                read_u32_be(bytes, 16)?,
+                read_u32_be(bytes, 20)?,
+            ))
+        },
+        | ImageMediaType::Gif => Some((
+            read_u16_le(bytes, 6)? as u32,
+            read_u16_le(bytes, 8)? as u32,
+        )),
+        | ImageMediaType::Jpeg => jpeg_dimensions(bytes),
+        | ImageMediaType::Webp => webp_dimensions(bytes),
+    }
+}
+
+/// Validates the image bytes against the limits of the API and returns the media type.
+pub(crate) fn validate_image(
+    bytes: &[u8]
+) -> ValidationResult<ImageMediaType, String> {
+    let media_type = sniff_image_media_type(bytes).ok_or_else(|| {
+        ValidationError {
+            _type: "ImageContentSource".to_string(),
+            expected: "The image must be JPEG, PNG, GIF or WebP.".to_string(),
+            actual: "unknown image format".to_string(),
+        }
+    })?;
+
+    let encoded_len = base64_len(bytes.len());
+    if encoded_len > MAX_IMAGE_SIZE {
+        return Err(ValidationError {
+            _type: "ImageContentSource".to_string(),
+            expected: format!(
+                "The maximum size of a base64 encoded image is {} bytes.",
+                MAX_IMAGE_SIZE
+            ),
+            actual: format!("{} bytes encoded in base64", encoded_len),
+        });
+    }
+
+    let (width, height) =
+        image_dimensions(bytes, media_type).ok_or_else(|| {
+            ValidationError {
+                _type: "ImageContentSource".to_string(),
+                expected: format!(
+                    "A valid {} image with the dimensions in the header.",
+                    media_type
+                ),
+                actual: "corrupted image header".to_string(),
+            }
+        })?;
+
+    if width > MAX_IMAGE_DIMENSION || height > MAX_IMAGE_DIMENSION {
+        return Err(ValidationError {
+            _type: "ImageContentSource".to_string(),
+            expected: format!(
+                "The maximum width and height of an image are {} pixels.",
+                MAX_IMAGE_DIMENSION
+            ),
+            actual: format!("{}x{} pixels", width, height),
+        });
+    }
+
+    Ok(media_type)
+}
+
+/// Reads the number of pages of the PDF from `/Count` of the root page tree node.
+///
+/// The catalog and the root page tree node are looked up in the plain objects and in the compressed object streams.
+/// It returns `None` if the number can not be read, e.g. the PDF is broken or the object streams are encrypted.
+pub(crate) fn count_pdf_pages(bytes: &[u8]) -> Option<usize> {
+    // The last trailer or cross-reference stream is the latest one.
+    let root = find_pdf_name(bytes, b"/Root", true)
+        .and_then(|offset| parse_pdf_reference(bytes, offset))?;
+    let catalog = find_pdf_object(bytes, root)?;
+
+    let pages = find_pdf_name(&catalog, b"/Pages", false)
+        .and_then(|offset| parse_pdf_reference(&catalog, offset))?;
+    let pages = find_pdf_object(bytes, pages)?;
+
+    let count = find_pdf_name(&pages, b"/Count", false)?;
+    match parse_pdf_reference(&pages, count) {
+        // The count of an indirect object.
+        | Some(reference) => {
+            let object = find_pdf_object(bytes, reference)?;
+            parse_pdf_integer(&object, 0).map(|(count, _)| count)
+        },
+        | None => parse_pdf_integer(&pages, count).map(|(count, _)| count),
+    }
+}
+/// Validates the PDF bytes against the limits of the API.
+pub(crate) fn validate_pdf(bytes: &[u8]) -> ValidationResult<(), String> {
+    if !bytes.starts_with(b"%PDF-") {
+        return Err(ValidationError {
+            _type: "DocumentContentSource".to_string(),
+            expected: "The document must be PDF.".to_string(),
+            actual: "unknown document format".to_string(),
+        });
+    }
+
+    if bytes.len() > MAX_PDF_SIZE {
+        return Err(ValidationError {
+            _type: "DocumentContentSource".to_string(),
+            expected: format!(
+                "The maximum size of a PDF document is {} bytes.",
+                MAX_PDF_SIZE
+            ),
+            actual: format!("{} bytes", bytes.len()),
+        });
+    }
+
+    // The number of pages that can not be read is left to the API to validate.
+    let pages = count_pdf_pages(bytes).unwrap_or_default();
+    if pages > MAX_PDF_PAGES {
+        return Err(ValidationError {
+            _type: "DocumentContentSource".to_string(),
+            expected: format!(
+                "The maximum number of pages of a PDF document is {}.",
+                MAX_PDF_PAGES
+            ),
+            actual: format!("{} pages", pages),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns whether the byte is a whitespace or a delimiter of the PDF syntax.
+fn is_pdf_separator(byte: u8) -> bool {
+    byte.is_ascii_whitespace()
+        || byte == b'\0'
+        || b"()<>[]{}/%".contains(&byte)
+}
+
+/// Finds the name token in the PDF bytes and returns the offset after it.
+fn find_pdf_name(
+    bytes: &[u8],
+    name: &[u8],
+    last: bool,
+) -> Option<usize> {
+    let mut found = None;
+    let mut offset = 0;
+    while let Some(position) = bytes[offset..]
+        .windows(name.len())
+        .position(|window| window == name)
+    {
+        offset += position + name.len();
+
+        // Exclude the longer names, e.g. `/PagesX` for `/Pages`.
+        if bytes
+            .get(offset)
+            .map_or(true, |byte| is_pdf_separator(*byte))
+        {
+            found = Some(offset);
+            if !last {
+                break;
+            }
+        }
+    }
+
+    found
+}
+
+/// Skips the whitespaces from the offset of the PDF bytes.
+fn skip_pdf_whitespace(
+    bytes: &[u8],
+    mut offset: usize,
+) -> usize {
+    while bytes
+        .get(offset)
+        .map_or(false, |byte| {
+            byte.is_ascii_whitespace() || *byte == b'\0'
+        })
+    {
+        offset += 1;
+    }
+
+    offset
+}
+
+/// Parses the non-negative integer at the offset of the PDF bytes and returns it with the offset after it.
+fn parse_pdf_integer(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<(usize, usize)> {
+    let start = skip_pdf_whitespace(bytes, offset);
+    let end = start
+        + bytes[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+    let value = std::str::from_utf8(&bytes[start..end])
+        .ok()?
+        .parse()
+        .ok()?;
+
+    Some((value, end))
+}
+
+/// Parses the indirect reference `<number> <generation> R` at the offset of the PDF bytes.
+fn parse_pdf_reference(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<(usize, usize)> {
+    let (number, offset) = parse_pdf_integer(bytes, offset)?;
+    let (generation, offset) = parse_pdf_integer(bytes, offset)?;
+    let offset = skip_pdf_whitespace(bytes, offset);
+
+    if bytes.get(offset) == Some(&b'R')
+        && bytes
+            .get(offset + 1)
+            .map_or(true, |byte| is_pdf_separator(*byte))
+    {
+        Some((number, generation))
+    } else {
+        None
+    }
+}
+
+/// Finds the body of the indirect object in the plain objects or in the compressed object streams.
+fn find_pdf_object(
+    bytes: &[u8],
+    (number, generation): (usize, usize),
+) -> Option<Vec<u8>> {
+    let header = format!("{} {} obj", number, generation);
+    let header = header.as_bytes();
+
+    // The last definition is the latest one updated incrementally.
+    let mut found = None;
+    let mut offset = 0;
+    while let Some(position) = bytes[offset..]
+        .windows(header.len())
+        .position(|window| window == header)
+    {
+        let start = offset + position;
+        offset = start + header.len();
+
+        // Exclude the longer object numbers, e.g. `11 0 obj` for `1 0 obj`.
+        if start == 0 || !bytes[start - 1].is_ascii_digit() {
+            found = Some(offset);
+        }
+    }
+
+    match found {
+        | Some(start) => {
+            let end = bytes[start..]
+                .windows(b"endobj".len())
+                .position(|window| window == b"endobj")
+                .map_or(bytes.len(), |position| start + position);
+
+            Some(bytes[start..end].to_vec())
+        },
+        // Objects in object streams always have the generation number zero.
+        | None if generation == 0 => find_pdf_object_in_streams(bytes, number),
+        | None => None,
+    }
+}
+
+/// Finds the object in the object streams (`/Type /ObjStm`) compressed by `/FlateDecode`.
+fn find_pdf_object_in_streams(
+    bytes: &[u8],
+    number: usize,
+) -> Option<Vec<u8>> {
+    let mut found = None;
+    let mut offset = 0;
+    while let Some(position) = find_pdf_name(&bytes[offset..], b"/ObjStm", false)
+    {
+        let type_offset = offset + position;
+        offset = type_offset;
+
+        // The dictionary of the stream between the object header and the `stream` keyword.
+        let Some(dictionary_start) = bytes[..type_offset]
+            .windows(b"obj".len())
+            .rposition(|window| window == b"obj")
+        else {
+            continue;
+        };
+        let Some(stream_keyword) = bytes[type_offset..]
+            .windows(b"stream".len())
+            .position(|window| window == b"stream")
+            .map(|position| type_offset + position)
+        else {
+            continue;
+        };
+        let dictionary = &bytes[dictionary_start..stream_keyword];
+
+        if let Some(object) =
+            read_pdf_object_stream(bytes, dictionary, stream_keyword)
+                .and_then(|stream| {
+                    find_object_in_stream(&stream, dictionary, number)
+                })
+        {
+            found = Some(object);
+        }
+    }
+
+    found
+}
+
+/// Reads the decoded data of the object stream, whose `stream` keyword is at the offset.
+fn read_pdf_object_stream(
+    bytes: &[u8],
+    dictionary: &[u8],
+    stream_keyword: usize,
+) -> Option<Vec<u8>> {
+    // The data follows the end of line after the `stream` keyword.
+    let mut start = stream_keyword + b"stream".len();
+    if bytes.get(start) == Some(&b'\r') {
+        start += 1;
+    }
+    if bytes.get(start) == Some(&b'\n') {
+        start += 1;
+    }
+
+    let length = find_pdf_name(dictionary, b"/Length", false)
+        .filter(|offset| parse_pdf_reference(dictionary, *offset).is_none())
+        .and_then(|offset| parse_pdf_integer(dictionary, offset))
+        .map(|(length, _)| length);
+    let end = match length {
+        | Some(length) => start.checked_add(length)?,
+        // The length of an indirect object is ended by the `endstream` keyword.
+        | None => {
+            start
+                + bytes[start..]
+                    .windows(b"endstream".len())
+                    .position(|window| window == b"endstream")?
+        },
+    };
+    let data = bytes.get(start..end)?;
+
+    // Predictors are not used for object streams in practice.
+    if find_pdf_name(dictionary, b"/DecodeParms", false).is_some() {
+        return None;
+    }
+    if find_pdf_name(dictionary, b"/FlateDecode", false).is_some() {
+        miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(
+            data,
+            MAX_PDF_SIZE,
+        )
+        .ok()
+    } else if find_pdf_name(dictionary, b"/Filter", false).is_some() {
+        None
+    } else {
+        Some(data.to_vec())
+    }
+}
+
+/// Finds the object in the decoded data of the object stream
+/// by the pairs of the object number and the offset in the header.
+fn find_object_in_stream(
+    stream: &[u8],
+    dictionary: &[u8],
+    number: usize,
+) -> Option<Vec<u8>> {
+    let (count, _) = find_pdf_name(dictionary, b"/N", false)
+        .and_then(|offset| parse_pdf_integer(dictionary, offset))?;
+    let (first, _) = find_pdf_name(dictionary, b"/First", false)
+        .and_then(|offset| parse_pdf_integer(dictionary, offset))?;
+    let header = stream.get(..first)?;
+
+    let mut offsets = Vec::with_capacity(count.min(header.len()));
+    let mut offset = 0;
+    for _ in 0..count {
+        let (object_number, next) = parse_pdf_integer(header, offset)?;
+        let (object_offset, next) = parse_pdf_integer(header, next)?;
+        offsets.push((object_number, object_offset));
+        offset = next;
+    }
+
+    let index = offsets
+        .iter()
+        .position(|(object_number, _)| *object_number == number)?;
+    let start = first.checked_add(offsets[index].1)?;
+    let end = offsets
+        .get(index + 1)
+        .and_then(|(_, offset)| first.checked_add(*offset))
+        .unwrap_or(stream.len());
+
+    stream
+        .get(start..end)
+        .map(<[u8]>::to_vec)
+}
+
+/// Reads the dimensions from the start of frame segment of the JPEG.
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    // Skip the start of image marker.
+    let mut offset = 2;
+
+    loop {
+        // Skip the fill bytes before the marker.
+        while *bytes.get(offset)? == 0xff {
+            offset += 1;
+        }
+        let marker = *bytes.get(offset)?;
+        offset += 1;
+
+        match marker {
+            // Standalone markers without the segment length.
+            | 0x01 | 0xd0..=0xd7 => continue,
+            // Start of frame markers except DHT, JPG and DAC.
+            | 0xc0..=0xc3 | 0xc5..=0xc7 | 0xc9..=0xcb | 0xcd..=0xcf => {
+                // The length and the sample precision precede the height and width.
+                let height = read_u16_be(bytes, offset + 3)?;
+                let width = read_u16_be(bytes, offset + 5)?;
+                return Some((width as u32, height as u32));
+            },
+            // End of image or start of scan before any frame.
+            | 0xd9 | 0xda => return None,
+            | _ => {
+                let length = read_u16_be(bytes, offset)? as usize;
+                offset += length;
+            },
+        }
+    }
+}
+
+/// Reads the dimensions from the first chunk of the WebP.
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        // Lossy: the frame tag and the start code precede the 14 bits width and height.
+        | b"VP8 " => Some((
+            (read_u16_le(bytes, 26)? & 0x3fff) as u32,
+            (read_u16_le(bytes, 28)? & 0x3fff) as u32,
+        )),
+        // Lossless: the signature precedes the 14 bits width and height minus one.
+        | b"VP8L" => {
+            let bits = read_u32_le(bytes, 21)?;
+            Some((
+                (bits & 0x3fff) + 1,
+                ((bits >> 14) & 0x3fff) + 1,
+            ))
+        },
+        // Extended: the flags precede the 24 bits canvas width and height minus one.
+        | b"VP8X" => Some((
+            read_u24_le(bytes, 24)? + 1,
+            read_u24_le(bytes, 27)? + 1,
+        )),
+        | _ => None,
+    }
+}
+
+fn read_u16_be(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u16> {
+    let slice = bytes.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([slice[0], slice[1]]))
+}
+
+fn read_u16_le(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u16> {
+    let slice = bytes.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([slice[0], slice[1]]))
+}
+
+fn read_u24_le(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u32> {
+    let slice = bytes.get(offset..offset + 3)?;
+    Some(u32::from_le_bytes([
+        slice[0], slice[1], slice[2], 0,
+    ]))
+}
+
+fn read_u32_be(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u32> {
+    let slice = bytes.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([
+        slice[0], slice[1], slice[2], slice[3],
+    ]))
+}
+
+fn read_u32_le(
+    bytes: &[u8],
+    offset: usize,
+) -> Option<u32> {
+    let slice = bytes.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([
+        slice[0], slice[1], slice[2], slice[3],
+    ]))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// The header of a PNG image with the dimensions.
+    pub(crate) fn png_header(
+        width: u32,
+        height: u32,
+    ) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+        bytes
+    }
+
+    #[test]
+    fn sniff() {
+        assert_eq!(
+            sniff_image_media_type(&png_header(1, 1)),
+            Some(ImageMediaType::Png)
+        );
+        assert_eq!(
+            sniff_image_media_type(b"\xff\xd8\xff\xe0"),
+            Some(ImageMediaType::Jpeg)
+        );
+        assert_eq!(
+            sniff_image_media_type(b"GIF89a\x01\x00\x01\x00"),
+            Some(ImageMediaType::Gif)
+        );
+        assert_eq!(
+            sniff_image_media_type(b"RIFF\x00\x00\x00\x00WEBPVP8 "),
+            Some(ImageMediaType::Webp)
+        );
+        assert_eq!(
+            sniff_image_media_type(b"%PDF-1.7"),
+            None
+        );
+    }
+
+    #[test]
+    fn dimensions() {
+        assert_eq!(
+            image_dimensions(&png_header(640, 480), ImageMediaType::Png),
+            Some((640, 480))
+        );
+        assert_eq!(
+            image_dimensions(
+                b"GIF89a\x80\x02\xe0\x01",
+                ImageMediaType::Gif
+            ),
+            Some((640, 480))
+        );
+
+        // APP0 segment, then SOF0 with 8 bits precision, 480 height and 640 width.
+        let jpeg = b"\xff\xd8\xff\xe0\x00\x04\x00\x00\xff\xc0\x00\x11\x08\x01\xe0\x02\x80\x03";
+        assert_eq!(
+            image_dimensions(jpeg, ImageMediaType::Jpeg),
+            Some((640, 480))
+        );
+
+        let mut webp = b"RIFF\x00\x00\x00\x00WEBPVP8X\x0a\x00\x00\x00\x00\x00\x00\x00"
+            .to_vec();
+        webp.extend_from_slice(&[0x7f, 0x02, 0x00, 0xdf, 0x01, 0x00]);
+        assert_eq!(
+            image_dimensions(&webp, ImageMediaType::Webp),
+            Some((640, 480))
+        );
+
+        assert_eq!(
+            image_dimensions(b"\x89PNG", ImageMediaType::Png),
+            None
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            validate_image(&png_header(8000, 8000)).unwrap(),
+            ImageMediaType::Png
+        );
+        assert!(validate_image(&png_header(8001, 1)).is_err());
+        assert!(validate_image(b"not an image").is_err());
+
+        // The limit applies to the base64 encoded data, which is 4/3 larger.
+        let mut largest = png_header(1, 1);
+        largest.resize(MAX_IMAGE_SIZE / 4 * 3, 0);
+        assert_eq!(
+            encode_base64(&largest).len(),
+            MAX_IMAGE_SIZE
+        );
+        assert!(validate_image(&largest).is_ok());
+
+        let mut large = largest;
+        large.push(0);
+        assert!(validate_image(&large).is_err());
+    }
+
+    #[test]
+    fn base64_length() {
+        for len in 0..8 {
+            assert_eq!(
+                base64_len(len),
+                encode_base64(&vec![0; len]).len()
+            );
+        }
+    }
+
+    /// The minimal PDF with the pages.
+    pub(crate) fn pdf(pages: usize) -> Vec<u8> {
+        let kids = (0..pages)
+            .map(|index| format!("{} 0 R", index + 3))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut bytes = format!(
+            "%PDF-1.7\n1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj\n2 0 obj << /Type /Pages /Kids [{}] /Count {} >> endobj\n",
+            kids, pages
+        )
+        .into_bytes();
+        for index in 0..pages {
+            bytes.extend_from_slice(
+                format!(
+                    "{} 0 obj << /Type/Page /Parent 2 0 R >> endobj\n",
+                    index + 3
+                )
+                .as_bytes(),
+            );
+        }
+        bytes.extend_from_slice(
+            b"trailer << /Size 3 /Root 1 0 R >>\n%%EOF\n",
+        );
+        bytes
+    }
+
+    /// The PDF whose catalog and root page tree node are in a compressed object stream.
+    fn pdf_with_object_stream(pages: usize) -> Vec<u8> {
+        let catalog = "<< /Type /Catalog /Pages 12 0 R >>\n";
+        let objects = format!(
+            "{}<< /Type /Pages /Kids [] /Count {} >>\n",
+            catalog, pages
+        );
+        let header = format!("11 0 12 {} ", catalog.len());
+        let data = miniz_oxide::deflate::compress_to_vec_zlib(
+            format!("{}{}", header, objects).as_bytes(),
+            6,
+        );
+
+        let mut bytes = format!(
+            "%PDF-1.7\n5 0 obj\n<< /Type /ObjStm /N 2 /First {} /Filter /FlateDecode /Length {} >>\nstream\n",
+            header.len(),
+            data.len()
+        )
+        .into_bytes();
+        bytes.extend_from_slice(&data);
+        bytes.extend_from_slice(
+            b"\nendstream\nendobj\n6 0 obj\n<< /Type /XRef /Size 13 /Root 11 0 R >>\nstream\nendstream\nendobj\n%%EOF\n",
+        );
+        bytes
+    }
+
+    #[test]
+    fn pdf_pages() {
+        assert_eq!(count_pdf_pages(&pdf(0)), Some(0));
+        assert_eq!(count_pdf_pages(&pdf(3)), Some(3));
+        assert_eq!(
+            count_pdf_pages(&pdf_with_object_stream(150)),
+            Some(150)
+        );
+        assert_eq!(
+            count_pdf_pages(b"%PDF-1.7\n1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj\n2 0 obj << /Type /Pages /Count 3 0 R >> endobj\n3 0 obj 7 endobj\n11 0 obj << /Count 1 >> endobj\ntrailer << /Root 1 0 R >>"),
+            Some(7)
+        );
+        assert_eq!(count_pdf_pages(b"/Type /Page"), None);
+        assert_eq!(
+            count_pdf_pages(b"%PDF-1.7\ntrailer << /Root 1 0 R >>"),
+            None
+        );
+    }
+
+    #[test]
+    fn validate_pdf_limits() {
+        assert!(validate_pdf(&pdf(MAX_PDF_PAGES)).is_ok());
+        assert!(validate_pdf(&pdf(MAX_PDF_PAGES + 1)).is_err());
+        assert!(validate_pdf(&pdf_with_object_stream(MAX_PDF_PAGES + 1)).is_err());
+        assert!(validate_pdf(&png_header(1, 1)).is_err());
+
+        let mut large = pdf(1);
+        large.resize(MAX_PDF_SIZE + 1, 0);
+        assert!(validate_pdf(&large).is_err());
+    }
+}
@@ -0,0 +1,511 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::{ready, Stream};
+use pin_project::pin_project;
+
+use crate::messages::{
+    ChunkStreamResult, Content, ContentBlock, MessagesResponseBody,
+    StreamChunk, StreamError, ToolUseAccumulator,
+};
+
+/// Accumulates stream chunks into the message.
+///
+/// It merges the `message_start`, the content blocks by index, and the stop reason and usage of the `message_delta`,
+/// then builds the same [`MessagesResponseBody`] as the non-streaming API.
+///
+/// ## Example
+/// ```no_run
+/// use clust::messages::MessageAccumulator;
+/// use futures_util::StreamExt;
+///
+/// # async fn example(mut stream: impl futures_core::Stream<Item = clust::messages::ChunkStreamResult> + Unpin) -> anyhow::Result<()> {
+/// let mut accumulator = MessageAccumulator::new();
+/// while let Some(chunk) = stream.next().await {
+///     accumulator.push(&chunk?)?;
+///     println!("Snapshot: {}", accumulator.snapshot());
+/// }
+///
+/// let response = accumulator.into_response()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageAccumulator {
+    /// The message of the `message_start` chunk.
+    message: Option<MessagesResponseBody>,
+    /// The content blocks by index.
+    content_blocks: BTreeMap<u32, ContentBlock>,
+    /// The accumulator of the tool use inputs.
+    tool_uses: ToolUseAccumulator,
+    /// Whether the `message_stop` chunk has arrived.
+    stopped: bool,
+}
+
+impl MessageAccumulator {
+    /// Creates a new message accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes a stream chunk to the accumulator.
+    ///
+    /// ## Arguments
+    /// - `chunk` - The stream chunk.
+    ///
+    /// ## Errors
+    /// It returns an error if the chunk does not match the accumulated content blocks.
+    pub fn push(
+        &mut self,
+        chunk: &StreamChunk,
+    ) -> Result<(), StreamError> {
+        if let Some(tool_use) = self.tool_uses.push(chunk)? {
+            if let StreamChunk::ContentBlockStop(content_block_stop) = chunk {
+                self.content_blocks.insert(
+                    content_block_stop.index,
+                    ContentBlock::ToolUse(tool_use),
+                );
+            }
+        }
+
+        match chunk {
+            | StreamChunk::MessageStart(message_start) => {
+                self.message = Some(message_start.message.clone());
+            },
+            | StreamChunk::ContentBlockStart(content_block_start) => {
+                self.content_blocks.insert(
+                    content_block_start.index,
+                    content_block_start
+                        .content_block
+                        .clone(),
+                );
+            },
+            | StreamChunk::ContentBlockDelta(content_block_delta) => {
+                let content_block = self
+                    .content_blocks
+                    .get_mut(&content_block_delta.index)
+                    .ok_or_else(|| {
+                        StreamError::UnexpectedChunk(format!(
+                            "Content block delta for unknown index: {}",
+                            content_block_delta.index
+                        ))
+                    })?;
+
+                match (content_block, &content_block_delta.delta) {
+                    | (
+                        ContentBlock::Text(text),
+                        ContentBlock::TextDelta(text_delta),
+                    ) => {
+                        text.text
+                            .push_str(&text_delta.text);
+                    },
+                    | (
+                        ContentBlock::Text(text),
+                        ContentBlock::CitationsDelta(citations_delta),
+                    ) => {
+                        text.citations
+                            .get_or_insert_with(Vec::new)
+                            .push(
+                                citations_delta
+                                    .citation
+                                    .clone(),
+                            );
+                    },
+                    | (
+                        ContentBlock::Thinking(thinking),
+                        ContentBlock::ThinkingDelta(thinking_delta),
+                    ) => {
+                        thinking
+                            .thinking
+                            .push_str(&thinking_delta.thinking);
+                    },
+                    | (
+                        ContentBlock::Thinking(thinking),
+                        ContentBlock::SignatureDelta(signature_delta),
+                    ) => {
+                        thinking
+                            .signature
+                            .push_str(&signature_delta.signature);
+                    },
+                    | (
+                        ContentBlock::ToolUse(_),
+                        ContentBlock::InputJsonDelta(_),
+                    ) => {
+                        // Accumulated by the tool use accumulator.
+                    },
+                    | (_, delta) => {
+                        return Err(StreamError::UnexpectedChunk(format!(
+                            "Content block delta does not match the content block at index {}: {}",
+                            content_block_delta.index, delta
+                        )));
+                    },
+                }
+            },
+            | StreamChunk::MessageDelta(message_delta) => {
+                let message = self
+                    .message
+                    .as_mut()
+                    .ok_or_else(|| {
+                        StreamError::UnexpectedChunk(
+                            "Message delta before message start".to_string(),
+                        )
+                    })?;
+                message.stop_reason = message_delta.delta.stop_reason;
+                message.stop_sequence = message_delta
+                    .delta
+                    .stop_sequence
+                    .clone();
+                message.usage.output_tokens = message_delta
+                    .usage
+                    .output_tokens;
+            },
+            | StreamChunk::MessageStop(_) => {
+                self.stopped = true;
+            },
+            | StreamChunk::Error(error) => {
+                return Err(error.clone().into());
+            },
+            | StreamChunk::Ping(_)
+            | StreamChunk::ContentBlockStop(_)
+            | StreamChunk::Unknown(_) => {},
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the `message_stop` chunk has arrived.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Returns the snapshot of the message accumulated so far.
+    ///
+    /// The input of a tool use is filled after its content block stops.
+    pub fn snapshot(&self) -> MessagesResponseBody {
+        let mut message = self
+            .message
+            .clone()
+            .unwrap_or_default();
+        message.content = Content::MultipleBlock(
+            self.content_blocks
+                .values()
+                .cloned()
+                .collect(),
+        );
+        message
+    }
+
+    /// Builds the accumulated message.
+    ///
+    /// ## Errors
+    /// It returns an error if the `message_start` chunk has not arrived.
+    pub fn into_response(self) -> Result<MessagesResponseBody, StreamError> {
+        if self.message.is_none() {
+            return Err(StreamError::UnexpectedChunk(
+                "Stream ended without message start".to_string(),
+            ));
+        }
+
+        Ok(self.snapshot())
+    }
+}
+
+/// An extension trait for the stream of message chunks.
+pub trait MessageStreamExt: Stream<Item = ChunkStreamResult> + Sized {
+    /// Consumes the stream and accumulates chunks into the message.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::messages::MessageStreamExt;
+    ///
+    /// # async fn example(stream: impl futures_core::Stream<Item = clust::messages::ChunkStreamResult>) -> anyhow::Result<()> {
+    /// let response = stream.accumulate().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn accumulate(self) -> AccumulateMessage<Self> {
+        AccumulateMessage {
+            stream: self,
+            accumulator: MessageAccumulator::new(),
+        }
+    }
+}
+
+impl<S> MessageStreamExt for S where S: Stream<Item = ChunkStreamResult> {}
+
+/// The future to accumulate the stream of message chunks.
+#[pin_project]
+pub struct AccumulateMessage<S>
+where
+    S: Stream<Item = ChunkStreamResult>,
+{
+    #[pin]
+    stream: S,
+    accumulator: MessageAccumulator,
+}
+
+impl<S> Future for AccumulateMessage<S>
+where
+    S: Stream<Item = ChunkStreamResult>,
+{
+    type Output = Result<MessagesResponseBody, StreamError>;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match ready!(this
+                .stream
+                .as_mut()
+                .poll_next(cx))
+            {
+                | Some(Ok(chunk)) => {
+                    this.accumulator.push(&chunk)?;
+                },
+                | Some(Err(error)) => {
+                    return Poll::Ready(Err(error));
+                },
+                | None => {
+                    let accumulator = std::mem::take(this.accumulator);
+                    return Poll::Ready(accumulator.into_response());
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::*;
+
+    fn chunks() -> Vec<StreamChunk> {
+        vec![
+            StreamChunk::MessageStart(MessageStartChunk::new(
+                MessagesResponseBody {
+                    id: "msg_01".to_string(),
+                    content: vec![].into(),
+                    model: ClaudeModel::Claude3Opus20240229,
+                    role: Role::Assistant,
+                    usage: Usage {
+                        input_tokens: 25,
+                        output_tokens: 1,
+                        cache_creation_input_tokens: None,
+                        cache_read_input_tokens: None,
+                    },
+                    ..Default::default()
+                },
+            )),
+            StreamChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                0,
+                TextContentBlock::new(""),
+            )),
+            StreamChunk::Ping(PingChunk::new()),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                TextDeltaContentBlock::new("Hello"),
+            )),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                TextDeltaContentBlock::new("!"),
+            )),
+            StreamChunk::ContentBlockStop(ContentBlockStopChunk::new(0)),
+            StreamChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                1,
+                ToolUseContentBlock::new(
+                    "toolu_01",
+                    "get_weather",
+                    serde_json::json!({}),
+                ),
+            )),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                1,
+                InputJsonDeltaContentBlock::new("{\"location\":"),
+            )),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                1,
+                InputJsonDeltaContentBlock::new("\"Tokyo\"}"),
+            )),
+            StreamChunk::ContentBlockStop(ContentBlockStopChunk::new(1)),
+            StreamChunk::MessageDelta(MessageDeltaChunk::new(
+                StreamStop {
+                    stop_reason: Some(StopReason::ToolUse),
+                    stop_sequence: None,
+                },
+                DeltaUsage {
+                    output_tokens: 15,
+                },
+            )),
+            StreamChunk::MessageStop(MessageStopChunk::new()),
+        ]
+    }
+
+    fn expected() -> MessagesResponseBody {
+        MessagesResponseBody {
+            id: "msg_01".to_string(),
+            _type: MessageObjectType::Message,
+            role: Role::Assistant,
+            content: vec![
+                ContentBlock::Text(TextContentBlock::new("Hello!")),
+                ContentBlock::ToolUse(ToolUseContentBlock::new(
+                    "toolu_01",
+                    "get_weather",
+                    serde_json::json!({"location": "Tokyo"}),
+                )),
+            ]
+            .into(),
+            model: ClaudeModel::Claude3Opus20240229,
+            stop_reason: Some(StopReason::ToolUse),
+            stop_sequence: None,
+            usage: Usage {
+                input_tokens: 25,
+                output_tokens: 15,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+            },
+        }
+    }
+
+    #[test]
+    fn push() {
+        let mut accumulator = MessageAccumulator::new();
+        for chunk in chunks() {
+            accumulator.push(&chunk).unwrap();
+        }
+
+        assert!(accumulator.is_stopped());
+        assert_eq!(
+            accumulator.into_response().unwrap(),
+            expected()
+        );
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut accumulator = MessageAccumulator::new();
+        for chunk in chunks().into_iter().take(4) {
+            accumulator.push(&chunk).unwrap();
+        }
+
+        assert!(!accumulator.is_stopped());
+        let snapshot = accumulator.snapshot();
+        assert_eq!(snapshot.id, "msg_01");
+        assert_eq!(
+            snapshot.content,
+            vec![ContentBlock::Text(TextContentBlock::new(
+                "Hello"
+            ))]
+            .into()
+        );
+        assert_eq!(snapshot.stop_reason, None);
+    }
+
+    #[test]
+    fn push_citations_delta() {
+        let citation = Citation::CharLocation(CharLocationCitation {
+            cited_text: "The grass is green.".to_string(),
+            end_char_index: 20,
+            ..Default::default()
+        });
+
+        let mut accumulator = MessageAccumulator::new();
+        for chunk in chunks().into_iter().take(4) {
+            accumulator.push(&chunk).unwrap();
+        }
+        accumulator
+            .push(&StreamChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(
+                    0,
+                    CitationsDeltaContentBlock::new(citation.clone()),
+                ),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            accumulator.snapshot().content,
+            vec![ContentBlock::Text(TextContentBlock {
+                text: "Hello".to_string(),
+                citations: Some(vec![citation]),
+                ..Default::default()
+            })]
+            .into()
+        );
+    }
+
+    #[test]
+    fn push_thinking_delta() {
+        let mut accumulator = MessageAccumulator::new();
+        let chunks = vec![
+            chunks().remove(0),
+            StreamChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                0,
+                ThinkingContentBlock::new("", ""),
+            )),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                ThinkingDeltaContentBlock::new("Let me "),
+            )),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                ThinkingDeltaContentBlock::new("think."),
+            )),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                SignatureDeltaContentBlock::new("signature"),
+            )),
+            StreamChunk::ContentBlockStop(ContentBlockStopChunk::new(0)),
+        ];
+        for chunk in chunks {
+            accumulator.push(&chunk).unwrap();
+        }
+
+        assert_eq!(
+            accumulator.snapshot().content,
+            vec![ContentBlock::Thinking(ThinkingContentBlock::new(
+                "Let me think.",
+                "signature",
+            ))]
+            .into()
+        );
+    }
+
+    #[test]
+    fn push_invalid() {
+        let mut accumulator = MessageAccumulator::new();
+        assert!(accumulator
+            .push(&StreamChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(
+                    0,
+                    TextDeltaContentBlock::new("Hello")
+                )
+            ))
+            .is_err());
+        assert!(accumulator
+            .push(&StreamChunk::MessageDelta(
+                MessageDeltaChunk::default()
+            ))
+            .is_err());
+        assert!(MessageAccumulator::new()
+            .into_response()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn accumulate() {
+        let stream = futures_util::stream::iter(
+            chunks()
+                .into_iter()
+                .map(Ok::<StreamChunk, StreamError>),
+        );
+
+        assert_eq!(
+            stream.accumulate().await.unwrap(),
+            expected()
+        );
+    }
+}
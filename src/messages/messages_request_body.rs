@@ -1,8 +1,10 @@
 use crate::macros::impl_display_for_serialize;
 use crate::messages::{
     ClaudeModel, MaxTokens, Message, Metadata, StopSequence, StreamOption,
-    SystemPrompt, Temperature, TopK, TopP,
+    SystemPrompt, Temperature, ThinkingConfig, ToolChoice, ToolDefinition,
+    TopK, TopP,
 };
+use crate::{ValidationError, ValidationResult};
 
 /// The request body for the Messages API.
 ///
@@ -70,20 +72,142 @@ pub struct MessagesRequestBody {
     /// Recommended for advanced use cases only. You usually only need to use temperature.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_k: Option<TopK>,
+    /// Definitions of tools that the model may use.
+    ///
+    /// If you include tools in your API request, the model may return tool_use content blocks that represent the model's use of those tools. You can then run those tools using the tool input generated by the model and then optionally return results back to the model using tool_result content blocks.
+    ///
+    /// See [tool use](https://docs.anthropic.com/claude/docs/tool-use) for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ToolDefinition>>,
+    /// How the model should use the provided tools.
+    ///
+    /// The model can use a specific tool, any available tool, or decide by itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
+    /// The configuration of extended thinking.
+    ///
+    /// When enabled, the response includes `thinking` content blocks of the reasoning before the final answer.
+    /// The budget tokens must be less than `max_tokens`.
+    ///
+    /// See [extended thinking](https://docs.anthropic.com/en/docs/build-with-claude/extended-thinking) for details.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<ThinkingConfig>,
 }
 
 impl_display_for_serialize!(MessagesRequestBody);
 
+impl MessagesRequestBody {
+    /// Validates the request body against the capabilities of the model.
+    ///
+    /// It checks the budget tokens of extended thinking against the maximum number of tokens,
+    /// then the maximum number of tokens, images for a model without vision, tools for a model without tool use
+    /// and extended thinking for a model without it.
+    /// A model whose capabilities are unknown is not validated against them, see [`crate::messages::ModelRegistry`].
+    ///
+    /// ## Errors
+    /// It returns a validation error with the description of the invalid request.
+    pub fn validate(&self) -> ValidationResult<(), String> {
+        if let Some(budget_tokens) = self
+            .thinking
+            .as_ref()
+            .and_then(ThinkingConfig::budget_tokens)
+        {
+            ThinkingConfig::validate_budget_tokens(
+                budget_tokens,
+                &self.max_tokens,
+            )
+            .map_err(|error| ValidationError {
+                _type: "MessagesRequestBody".to_string(),
+                expected: error.expected,
+                actual: format!(
+                    "thinking.budget_tokens: {}",
+                    error.actual
+                ),
+            })?;
+        }
+
+        let capabilities = match self.model.capabilities() {
+            | Some(capabilities) => capabilities,
+            | None => return Ok(()),
+        };
+
+        if self.max_tokens.value() > capabilities.max_output_tokens {
+            return Err(ValidationError {
+                _type: "MessagesRequestBody".to_string(),
+                expected: format!(
+                    "The maximum number of tokens for the model: {} is {}.",
+                    self.model, capabilities.max_output_tokens
+                ),
+                actual: format!(
+                    "max_tokens: {}",
+                    self.max_tokens
+                ),
+            });
+        }
+
+        if !capabilities.vision
+            && self
+                .messages
+                .iter()
+                .any(|message| message.content.has_image())
+        {
+            return Err(ValidationError {
+                _type: "MessagesRequestBody".to_string(),
+                expected: format!(
+                    "The model: {} does not support image inputs.",
+                    self.model
+                ),
+                actual: "messages with images".to_string(),
+            });
+        }
+
+        if !capabilities.tool_use
+            && (self.tools.is_some() || self.tool_choice.is_some())
+        {
+            return Err(ValidationError {
+                _type: "MessagesRequestBody".to_string(),
+                expected: format!(
+                    "The model: {} does not support tool use.",
+                    self.model
+                ),
+                actual: "tools".to_string(),
+            });
+        }
+
+        if !capabilities.thinking
+            && self
+                .thinking
+                .as_ref()
+                .and_then(ThinkingConfig::budget_tokens)
+                .is_some()
+        {
+            return Err(ValidationError {
+                _type: "MessagesRequestBody".to_string(),
+                expected: format!(
+                    "The model: {} does not support extended thinking.",
+                    self.model
+                ),
+                actual: "thinking enabled".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::messages::{
+        ContentBlock, ImageContentBlock, ModelCapabilities, ModelRegistry,
+    };
 
     #[test]
     fn new() {
         let messages_request_body = MessagesRequestBody {
             model: ClaudeModel::Claude3Sonnet20240229,
             messages: vec![],
-            max_tokens: MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229)
+            max_tokens: MaxTokens::new(16, &ClaudeModel::Claude3Sonnet20240229)
                 .unwrap(),
             ..Default::default()
         };
@@ -95,7 +219,7 @@ mod tests {
         assert_eq!(messages_request_body.system, None);
         assert_eq!(
             messages_request_body.max_tokens,
-            MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229).unwrap()
+            MaxTokens::new(16, &ClaudeModel::Claude3Sonnet20240229).unwrap()
         );
         assert_eq!(messages_request_body.metadata, None);
         assert_eq!(
@@ -106,6 +230,9 @@ mod tests {
         assert_eq!(messages_request_body.temperature, None);
         assert_eq!(messages_request_body.top_p, None);
         assert_eq!(messages_request_body.top_k, None);
+        assert_eq!(messages_request_body.tools, None);
+        assert_eq!(messages_request_body.tool_choice, None);
+        assert_eq!(messages_request_body.thinking, None);
     }
 
     #[test]
@@ -130,6 +257,9 @@ mod tests {
         assert_eq!(messages_request_body.temperature, None);
         assert_eq!(messages_request_body.top_p, None);
         assert_eq!(messages_request_body.top_k, None);
+        assert_eq!(messages_request_body.tools, None);
+        assert_eq!(messages_request_body.tool_choice, None);
+        assert_eq!(messages_request_body.thinking, None);
     }
 
     #[test]
@@ -152,7 +282,7 @@ mod tests {
         let messages_request_body = MessagesRequestBody {
             model: ClaudeModel::Claude3Sonnet20240229,
             messages: vec![],
-            max_tokens: MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229)
+            max_tokens: MaxTokens::new(16, &ClaudeModel::Claude3Sonnet20240229)
                 .unwrap(),
             system: Some(SystemPrompt::new("system-prompt")),
             metadata: Some(Metadata {
@@ -165,10 +295,17 @@ mod tests {
             temperature: Some(Temperature::new(0.5).unwrap()),
             top_p: Some(TopP::new(0.5).unwrap()),
             top_k: Some(TopK::new(50)),
+            tools: Some(vec![ToolDefinition::new(
+                "tool",
+                None,
+                serde_json::json!({"type": "object"}),
+            )]),
+            tool_choice: Some(ToolChoice::auto()),
+            thinking: None,
         };
         assert_eq!(
             serde_json::to_string(&messages_request_body).unwrap(),
-            "{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50}"
+            "{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50,\"tools\":[{\"name\":\"tool\",\"input_schema\":{\"type\":\"object\"}}],\"tool_choice\":{\"type\":\"auto\"}}"
         );
     }
 
@@ -183,7 +320,7 @@ mod tests {
         let messages_request_body = MessagesRequestBody {
             model: ClaudeModel::Claude3Sonnet20240229,
             messages: vec![],
-            max_tokens: MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229)
+            max_tokens: MaxTokens::new(16, &ClaudeModel::Claude3Sonnet20240229)
                 .unwrap(),
             system: Some(SystemPrompt::new("system-prompt")),
             metadata: Some(Metadata {
@@ -196,10 +333,140 @@ mod tests {
             temperature: Some(Temperature::new(0.5).unwrap()),
             top_p: Some(TopP::new(0.5).unwrap()),
             top_k: Some(TopK::new(50)),
+            tools: Some(vec![ToolDefinition::new(
+                "tool",
+                None,
+                serde_json::json!({"type": "object"}),
+            )]),
+            tool_choice: Some(ToolChoice::auto()),
+            thinking: None,
         };
         assert_eq!(
-            serde_json::from_str::<MessagesRequestBody>("{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50}").unwrap(),
+            serde_json::from_str::<MessagesRequestBody>("{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50,\"tools\":[{\"name\":\"tool\",\"input_schema\":{\"type\":\"object\"}}],\"tool_choice\":{\"type\":\"auto\"}}").unwrap(),
             messages_request_body
         );
     }
+
+    #[test]
+    fn validate() {
+        let request_body = MessagesRequestBody {
+            model: ClaudeModel::Claude3Sonnet20240229,
+            messages: vec![Message::user(vec![
+                ContentBlock::Image(ImageContentBlock::default()),
+            ])],
+            tools: Some(vec![]),
+            ..Default::default()
+        };
+        assert!(request_body.validate().is_ok());
+
+        let model = ClaudeModel::new("claude-validate-test");
+        assert!(MessagesRequestBody {
+            model: model.clone(),
+            ..request_body.clone()
+        }
+        .validate()
+        .is_ok());
+
+        ModelRegistry::register(
+            &model,
+            ModelCapabilities {
+                context_window: 100000,
+                max_output_tokens: 1024,
+                vision: false,
+                tool_use: false,
+                ..Default::default()
+            },
+        );
+        assert!(MessagesRequestBody {
+            model: model.clone(),
+            messages: vec![Message::user("Hello")],
+            max_tokens: MaxTokens::new(1024, &model).unwrap(),
+            ..Default::default()
+        }
+        .validate()
+        .is_ok());
+        assert!(MessagesRequestBody {
+            model: model.clone(),
+            messages: vec![Message::user("Hello")],
+            max_tokens: MaxTokens::default(),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+        assert!(MessagesRequestBody {
+            model: model.clone(),
+            max_tokens: MaxTokens::new(1024, &model).unwrap(),
+            tools: None,
+            ..request_body.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(MessagesRequestBody {
+            model: model.clone(),
+            messages: vec![Message::user("Hello")],
+            max_tokens: MaxTokens::new(1024, &model).unwrap(),
+            ..request_body.clone()
+        }
+        .validate()
+        .is_err());
+        ModelRegistry::unregister(&model);
+    }
+
+    #[test]
+    fn validate_thinking() {
+        let model = ClaudeModel::new("claude-thinking-test");
+        ModelRegistry::register(
+            &model,
+            ModelCapabilities {
+                context_window: 200000,
+                max_output_tokens: 8192,
+                thinking: true,
+                ..Default::default()
+            },
+        );
+
+        let max_tokens = MaxTokens::new(2048, &model).unwrap();
+        let request_body = MessagesRequestBody {
+            model: model.clone(),
+            max_tokens,
+            thinking: Some(ThinkingConfig::enabled(1024, &max_tokens).unwrap()),
+            ..Default::default()
+        };
+        assert!(request_body.validate().is_ok());
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            "{\"model\":\"claude-thinking-test\",\"messages\":[],\"max_tokens\":2048,\"thinking\":{\"type\":\"enabled\",\"budget_tokens\":1024}}"
+        );
+
+        // The maximum number of tokens is decreased after the thinking configuration.
+        assert!(MessagesRequestBody {
+            max_tokens: MaxTokens::new(1024, &model).unwrap(),
+            ..request_body.clone()
+        }
+        .validate()
+        .is_err());
+
+        // The model does not support extended thinking.
+        assert!(MessagesRequestBody {
+            model: ClaudeModel::Claude3Sonnet20240229,
+            ..request_body.clone()
+        }
+        .validate()
+        .is_err());
+        assert!(MessagesRequestBody {
+            model: ClaudeModel::Claude3Sonnet20240229,
+            thinking: Some(ThinkingConfig::disabled()),
+            ..request_body.clone()
+        }
+        .validate()
+        .is_ok());
+
+        assert!(MessagesRequestBody {
+            thinking: Some(ThinkingConfig::disabled()),
+            ..request_body
+        }
+        .validate()
+        .is_ok());
+        ModelRegistry::unregister(&model);
+    }
 }
@@ -2,7 +2,7 @@ use crate::macros::{
     impl_display_for_serialize, impl_enum_string_serialization,
 };
 use crate::messages::{
-    ClaudeModel, Content, Role, StopReason, StopSequence, Usage,
+    ClaudeModel, Content, Message, Role, StopReason, StopSequence, Usage,
 };
 use std::fmt::{Display, Formatter};
 
@@ -28,7 +28,7 @@ pub struct MessagesResponseBody {
     pub role: Role,
     /// Content generated by the model.
     ///
-    /// This is an array of content blocks, each of which has a type that determines its shape. Currently, the only type in responses is "text".
+    /// This is an array of content blocks, each of which has a type that determines its shape, e.g. "text" or "tool_use".
     pub content: Content,
     /// The model that handled the request.
     pub model: ClaudeModel,
@@ -39,6 +39,7 @@ pub struct MessagesResponseBody {
     /// "end_turn": the model reached a natural stopping point
     /// "max_tokens": we exceeded the requested max_tokens or the model's maximum
     /// "stop_sequence": one of your provided custom stop_sequences was generated
+    /// "tool_use": the model invoked one or more tools
     /// Note that these values are different from those in /v1/complete, where end_turn and stop_sequence were not differentiated.
     ///
     /// In non-streaming mode this value is always non-null. In streaming mode, it is null in the message_start event and non-null otherwise.
@@ -59,6 +60,25 @@ pub struct MessagesResponseBody {
 
 impl_display_for_serialize!(MessagesResponseBody);
 
+impl MessagesResponseBody {
+    /// Converts the response into the assistant message to replay it in the next request.
+    ///
+    /// All the content blocks are preserved as is, including the `thinking` blocks with the signatures and the `redacted_thinking` blocks,
+    /// which must be passed back unmodified when extended thinking is enabled, e.g. with tool use.
+    pub fn into_message(self) -> Message {
+        Message {
+            role: self.role,
+            content: self.content,
+        }
+    }
+}
+
+impl From<MessagesResponseBody> for Message {
+    fn from(response: MessagesResponseBody) -> Self {
+        response.into_message()
+    }
+}
+
 /// The object type for message.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MessageObjectType {
@@ -78,7 +98,7 @@ impl Display for MessageObjectType {
         f: &mut Formatter<'_>,
     ) -> std::fmt::Result {
         match self {
-            | MessageObjectType::Message => write!(f, "{}", "message"),
+            | MessageObjectType::Message => write!(f, "message"),
         }
     }
 }
@@ -105,6 +125,8 @@ mod tests {
             usage: Usage {
                 input_tokens: 1,
                 output_tokens: 2,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
             },
         };
         assert_eq!(
@@ -126,6 +148,8 @@ mod tests {
             usage: Usage {
                 input_tokens: 1,
                 output_tokens: 2,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
             },
         };
         assert_eq!(
@@ -136,6 +160,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_with_unknown_model() {
+        let response = serde_json::from_str::<MessagesResponseBody>(
+            "{\"id\":\"id\",\"type\":\"message\",\"role\":\"assistant\",\"content\":\"content\",\"model\":\"claude-next\",\"stop_reason\":\"end_turn\",\"stop_sequence\":null,\"usage\":{\"input_tokens\":1,\"output_tokens\":2}}"
+        )
+        .unwrap();
+        assert_eq!(
+            response.model,
+            ClaudeModel::Custom("claude-next".to_string())
+        );
+    }
+
+    #[test]
+    fn deserialize_with_cache_usage() {
+        let response = serde_json::from_str::<MessagesResponseBody>(
+            r#"{"id":"msg_01","type":"message","role":"assistant","content":[{"type":"text","text":"Hi"}],"model":"claude-3-haiku-20240307","stop_reason":"end_turn","stop_sequence":null,"usage":{"input_tokens":10,"cache_creation_input_tokens":2048,"cache_read_input_tokens":0,"output_tokens":3}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response.usage,
+            Usage {
+                input_tokens: 10,
+                output_tokens: 3,
+                cache_creation_input_tokens: Some(2048),
+                cache_read_input_tokens: Some(0),
+            }
+        );
+    }
+
     #[test]
     fn display() {
         let response = MessagesResponseBody {
@@ -149,6 +202,8 @@ mod tests {
             usage: Usage {
                 input_tokens: 1,
                 output_tokens: 2,
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
             },
         };
         assert_eq!(
@@ -188,4 +243,38 @@ mod tests {
             MessageObjectType::Message
         );
     }
+
+    #[test]
+    fn into_message_preserves_thinking() {
+        use crate::messages::{
+            ContentBlock, RedactedThinkingContentBlock, TextContentBlock,
+            ThinkingContentBlock,
+        };
+
+        let response = serde_json::from_str::<MessagesResponseBody>(
+            r#"{"id":"msg_01","type":"message","role":"assistant","content":[{"type":"thinking","thinking":"Let me think.","signature":"signature"},{"type":"redacted_thinking","data":"encrypted"},{"type":"text","text":"Hello!"}],"model":"claude-3-haiku-20240307","stop_reason":"end_turn","stop_sequence":null,"usage":{"input_tokens":10,"output_tokens":3}}"#,
+        )
+        .unwrap();
+
+        let message = Message::from(response);
+        assert_eq!(message.role, Role::Assistant);
+        assert_eq!(
+            message.content,
+            vec![
+                ContentBlock::Thinking(ThinkingContentBlock::new(
+                    "Let me think.",
+                    "signature",
+                )),
+                ContentBlock::RedactedThinking(
+                    RedactedThinkingContentBlock::new("encrypted")
+                ),
+                ContentBlock::Text(TextContentBlock::new("Hello!")),
+            ]
+            .into()
+        );
+        assert_eq!(
+            serde_json::to_string(&message).unwrap(),
+            r#"{"role":"assistant","content":[{"type":"thinking","thinking":"Let me think.","signature":"signature"},{"type":"redacted_thinking","data":"encrypted"},{"type":"text","text":"Hello!"}]}"#
+        );
+    }
 }
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use crate::messages::ClaudeModel;
+
+/// The capabilities of a model.
+///
+/// See [models](https://docs.anthropic.com/claude/docs/models-overview) for details.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ModelCapabilities {
+    /// The maximum number of input and output tokens.
+    pub context_window: u32,
+    /// The maximum number of output tokens.
+    pub max_output_tokens: u32,
+    /// Whether the model accepts image inputs.
+    pub vision: bool,
+    /// Whether the model supports tool use.
+    pub tool_use: bool,
+    /// Whether the model supports extended thinking.
+    pub thinking: bool,
+    /// The deprecation date of the model in `YYYY-MM-DD` if it has been announced.
+    ///
+    /// See [model deprecations](https://docs.anthropic.com/en/docs/resources/model-deprecations) for the retirement dates.
+    pub deprecation_date: Option<String>,
+    /// The pricing of the model if it is known.
+    pub pricing: Option<ModelPricing>,
+}
+
+/// The pricing of a model in USD.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ModelPricing {
+    /// The price per million input tokens.
+    pub input_per_million_tokens: f64,
+    /// The price per million output tokens.
+    pub output_per_million_tokens: f64,
+}
+
+impl ModelPricing {
+    /// Calculates the cost in USD.
+    ///
+    /// ## Arguments
+    /// - `input_tokens` - The number of input tokens.
+    /// - `output_tokens` - The number of output tokens.
+    pub fn cost(
+        &self,
+        input_tokens: u32,
+        output_tokens: u32,
+    ) -> f64 {
+        (input_tokens as f64 * self.input_per_million_tokens
+            + output_tokens as f64 * self.output_per_million_tokens)
+            / 1_000_000.0
+    }
+}
+
+/// The registry of model capabilities.
+///
+/// The capabilities of the models known to this crate are built in,
+/// and those of other models can be registered or overridden at runtime.
+///
+/// ## Example
+/// ```
+/// use clust::messages::{ClaudeModel, MaxTokens, ModelCapabilities, ModelRegistry};
+///
+/// let model = ClaudeModel::new("claude-next");
+/// ModelRegistry::register(
+///     &model,
+///     ModelCapabilities {
+///         context_window: 200000,
+///         max_output_tokens: 8192,
+///         vision: true,
+///         tool_use: true,
+///         ..Default::default()
+///     },
+/// );
+///
+/// assert!(MaxTokens::new(8192, &model).is_ok());
+/// assert!(MaxTokens::new(8193, &model).is_err());
+/// ```
+pub struct ModelRegistry;
+
+impl ModelRegistry {
+    /// Returns the capabilities of the model.
+    ///
+    /// A registered override takes precedence over the built-in capabilities.
+    /// It returns `None` for a model unknown to this crate that has not been registered.
+    ///
+    /// ## Arguments
+    /// - `model` - The target model.
+    pub fn get(model: &ClaudeModel) -> Option<ModelCapabilities> {
+        let overridden = overrides()
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .get(model)
+            .cloned();
+
+        overridden.or_else(|| built_in(model))
+    }
+
+    /// Registers or overrides the capabilities of the model.
+    ///
+    /// ## Arguments
+    /// - `model` - The target model.
+    /// - `capabilities` - The capabilities of the model.
+    pub fn register(
+        model: &ClaudeModel,
+        capabilities: ModelCapabilities,
+    ) {
+        overrides()
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+            .insert(model.clone(), capabilities);
+    }
+
+    /// Removes the registered capabilities of the model and restores the built-in ones.
+    ///
+    /// ## Arguments
+    /// - `model` - The target model.
+    pub fn unregister(model: &ClaudeModel) {
+        overrides()
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+            .remove(model);
+    }
+}
+
+/// The capabilities registered at runtime.
+fn overrides() -> &'static RwLock<HashMap<ClaudeModel, ModelCapabilities>> {
+    static OVERRIDES: OnceLock<
+        RwLock<HashMap<ClaudeModel, ModelCapabilities>>,
+    > = OnceLock::new();
+    OVERRIDES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// The built-in capabilities of the models known to this crate.
+fn built_in(model: &ClaudeModel) -> Option<ModelCapabilities> {
+    // Match the variant of the model ID, which may be held by a custom model.
+    match ClaudeModel::from(model.model_id()) {
+        | ClaudeModel::Claude3Opus20240229 => Some(ModelCapabilities {
+            context_window: 200000,
+            max_output_tokens: 4096,
+            vision: true,
+            tool_use: true,
+            thinking: false,
+            deprecation_date: Some("2025-06-30".to_string()),
+            pricing: Some(ModelPricing {
+                input_per_million_tokens: 15.0,
+                output_per_million_tokens: 75.0,
+            }),
+        }),
+        | ClaudeModel::Claude3Sonnet20240229 => Some(ModelCapabilities {
+            context_window: 200000,
+            max_output_tokens: 4096,
+            vision: true,
+            tool_use: true,
+            thinking: false,
+            deprecation_date: Some("2025-01-21".to_string()),
+            pricing: Some(ModelPricing {
+                input_per_million_tokens: 3.0,
+                output_per_million_tokens: 15.0,
+            }),
+        }),
+        | ClaudeModel::Claude3Haiku20240307 => Some(ModelCapabilities {
+            context_window: 200000,
+            max_output_tokens: 4096,
+            vision: true,
+            tool_use: true,
+            thinking: false,
+            deprecation_date: None,
+            pricing: Some(ModelPricing {
+                input_per_million_tokens: 0.25,
+                output_per_million_tokens: 1.25,
+            }),
+        }),
+        | ClaudeModel::Claude21 => Some(ModelCapabilities {
+            context_window: 200000,
+            max_output_tokens: 4096,
+            vision: false,
+            tool_use: false,
+            thinking: false,
+            deprecation_date: Some("2025-01-21".to_string()),
+            pricing: Some(ModelPricing {
+                input_per_million_tokens: 8.0,
+                output_per_million_tokens: 24.0,
+            }),
+        }),
+        | ClaudeModel::Claude20 => Some(ModelCapabilities {
+            context_window: 100000,
+            max_output_tokens: 4096,
+            vision: false,
+            tool_use: false,
+            thinking: false,
+            deprecation_date: Some("2025-01-21".to_string()),
+            pricing: Some(ModelPricing {
+                input_per_million_tokens: 8.0,
+                output_per_million_tokens: 24.0,
+            }),
+        }),
+        | ClaudeModel::ClaudeInstant12 => Some(ModelCapabilities {
+            context_window: 100000,
+            max_output_tokens: 4096,
+            vision: false,
+            tool_use: false,
+            thinking: false,
+            deprecation_date: Some("2024-09-04".to_string()),
+            pricing: Some(ModelPricing {
+                input_per_million_tokens: 0.8,
+                output_per_million_tokens: 2.4,
+            }),
+        }),
+        | ClaudeModel::Custom(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_built_in() {
+        let capabilities =
+            ModelRegistry::get(&ClaudeModel::Claude3Opus20240229).unwrap();
+        assert_eq!(capabilities.context_window, 200000);
+        assert_eq!(capabilities.max_output_tokens, 4096);
+        assert!(capabilities.vision);
+        assert!(capabilities.tool_use);
+        assert_eq!(
+            capabilities.deprecation_date,
+            Some("2025-06-30".to_string())
+        );
+        assert_eq!(
+            ModelRegistry::get(&ClaudeModel::ClaudeInstant12)
+                .unwrap()
+                .deprecation_date,
+            Some("2024-09-04".to_string())
+        );
+
+        assert_eq!(
+            ModelRegistry::get(&ClaudeModel::Custom(
+                "claude-3-haiku-20240307".to_string()
+            )),
+            ModelRegistry::get(&ClaudeModel::Claude3Haiku20240307)
+        );
+        assert_eq!(
+            ModelRegistry::get(&ClaudeModel::new("claude-unknown")),
+            None
+        );
+    }
+
+    #[test]
+    fn register() {
+        let model = ClaudeModel::new("claude-registry-test");
+        let capabilities = ModelCapabilities {
+            context_window: 100000,
+            max_output_tokens: 2048,
+            vision: false,
+            tool_use: false,
+            thinking: false,
+            deprecation_date: Some("2030-01-01".to_string()),
+            pricing: None,
+        };
+
+        ModelRegistry::register(&model, capabilities.clone());
+        assert_eq!(
+            ModelRegistry::get(&model),
+            Some(capabilities)
+        );
+
+        ModelRegistry::unregister(&model);
+        assert_eq!(ModelRegistry::get(&model), None);
+    }
+
+    #[test]
+    fn cost() {
+        let pricing = ModelPricing {
+            input_per_million_tokens: 3.0,
+            output_per_million_tokens: 15.0,
+        };
+        assert_eq!(
+            pricing.cost(1_000_000, 0),
+            3.0
+        );
+        assert_eq!(
+            pricing.cost(500_000, 100_000),
+            3.0
+        );
+    }
+}
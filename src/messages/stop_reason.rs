@@ -8,6 +8,7 @@ use std::fmt::Display;
 /// "end_turn": the model reached a natural stopping point
 /// "max_tokens": we exceeded the requested max_tokens or the model's maximum
 /// "stop_sequence": one of your provided custom stop_sequences was generated
+/// "tool_use": the model invoked one or more tools
 /// Note that these values are different from those in /v1/complete, where end_turn and stop_sequence were not differentiated.
 ///
 /// In non-streaming mode this value is always non-null. In streaming mode, it is null in the message_start event and non-null otherwise.
@@ -19,6 +20,8 @@ pub enum StopReason {
     MaxTokens,
     /// One of your provided custom stop_sequences was generated.
     StopSequence,
+    /// The model invoked one or more tools.
+    ToolUse,
 }
 
 impl Display for StopReason {
@@ -36,6 +39,9 @@ impl Display for StopReason {
             | StopReason::StopSequence => {
                 write!(f, "stop_sequence")
             },
+            | StopReason::ToolUse => {
+                write!(f, "tool_use")
+            },
         }
     }
 }
@@ -44,7 +50,8 @@ impl_enum_string_serialization!(
     StopReason,
     EndTurn => "end_turn",
     MaxTokens => "max_tokens",
-    StopSequence => "stop_sequence"
+    StopSequence => "stop_sequence",
+    ToolUse => "tool_use"
 );
 
 #[cfg(test)]
@@ -65,6 +72,10 @@ mod tests {
             StopReason::StopSequence.to_string(),
             "stop_sequence"
         );
+        assert_eq!(
+            StopReason::ToolUse.to_string(),
+            "tool_use"
+        );
     }
 
     #[test]
@@ -81,6 +92,10 @@ mod tests {
             serde_json::to_string(&StopReason::StopSequence).unwrap(),
             "\"stop_sequence\""
         );
+        assert_eq!(
+            serde_json::to_string(&StopReason::ToolUse).unwrap(),
+            "\"tool_use\""
+        );
     }
 
     #[test]
@@ -97,5 +112,9 @@ mod tests {
             serde_json::from_str::<StopReason>("\"stop_sequence\"").unwrap(),
             StopReason::StopSequence
         );
+        assert_eq!(
+            serde_json::from_str::<StopReason>("\"tool_use\"").unwrap(),
+            StopReason::ToolUse
+        );
     }
 }
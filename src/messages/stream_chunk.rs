@@ -6,9 +6,11 @@ use crate::macros::{
     impl_display_for_serialize, impl_enum_string_serialization,
 };
 use crate::messages::{
-    MessagesResponseBody, StopReason, StopSequence, StreamError,
-    TextContentBlock, TextDeltaContentBlock,
+    ContentBlock, MessagesResponseBody, StopReason, StopSequence, StreamError,
+    TextDeltaContentBlock,
 };
+use crate::sse::{SseDecoder, SseEvent};
+use crate::ApiErrorResponse;
 
 /// The stream chunk of messages.
 #[derive(Debug, Clone, PartialEq)]
@@ -27,6 +29,10 @@ pub enum StreamChunk {
     MessageDelta(MessageDeltaChunk),
     /// Message stop chunk.
     MessageStop(MessageStopChunk),
+    /// Error chunk, e.g. `overloaded_error`.
+    Error(ApiErrorResponse),
+    /// Chunk of an event type unknown to this crate.
+    Unknown(UnknownChunk),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -128,49 +134,77 @@ impl Display for StreamChunk {
                     message_stop._type, json
                 )
             },
+            | StreamChunk::Error(error) => {
+                let json = json_format
+                    .format_to_string(&error)
+                    .map_err(|_| std::fmt::Error)?;
+
+                write!(
+                    f,
+                    "event: {}\ndata: {}",
+                    StreamChunkType::Error,
+                    json
+                )
+            },
+            | StreamChunk::Unknown(unknown) => {
+                write!(
+                    f,
+                    "event: {}\ndata: {}",
+                    unknown.event, unknown.data
+                )
+            },
         }
     }
 }
 
 impl StreamChunk {
-    pub(crate) fn parse(source: &str) -> Result<StreamChunk, StreamError> {
-        let lines = source
-            .lines()
-            .collect::<Vec<&str>>();
-
-        // Check length
-        if lines.len() != 2 {
-            return Err(StreamError::ParseChunkStringError(
-                format!(
-                    "Chunk must be two lines but not: {}",
-                    source
-                ),
-            ));
-        }
-
-        // Parse the event segment to the chunk type.
-        let first_line = lines[0];
-        let event = first_line
-            .strip_prefix("event: ")
+    /// Parses the stream chunk from the text of a Server-Sent Event.
+    ///
+    /// ## Arguments
+    /// - `source` - The text of the event, e.g. `event: ping\ndata: {"type": "ping"}`.
+    ///
+    /// ## Errors
+    /// It returns an error if the text does not contain an event with data or the data cannot be deserialized.
+    pub fn parse(source: &str) -> Result<StreamChunk, StreamError> {
+        let mut decoder = SseDecoder::new();
+        decoder.push(source.as_bytes());
+        // Terminate the last event.
+        decoder.push(b"\n\n");
+
+        let event = decoder
+            .next_event()
             .ok_or_else(|| {
                 StreamError::ParseChunkStringError(format!(
-                    "First line must start with 'event: ', but not: {}",
+                    "Chunk must have an event with data, but not: {}",
                     source
                 ))
             })?;
-        let chunk_type = StreamChunkType::from_str(event)
-            .map_err(StreamError::ParseChunkStringError)?;
 
-        // Parse the data segment to the chunk data.
-        let second_line = lines[1];
-        let data = second_line
-            .strip_prefix("data: ")
-            .ok_or_else(|| {
-                StreamError::ParseChunkStringError(format!(
-                    "Second line must start with 'data: ', but not: {}",
-                    source
-                ))
-            })?;
+        Self::from_event(&event)
+    }
+
+    /// Converts the Server-Sent Event into the stream chunk.
+    ///
+    /// ## Arguments
+    /// - `event` - The event decoded by [`crate::sse::SseDecoder`].
+    ///
+    /// ## Errors
+    /// It returns an error if the data of the event cannot be deserialized.
+    pub fn from_event(
+        event: &SseEvent,
+    ) -> Result<StreamChunk, StreamError> {
+        let data = event.data.as_str();
+
+        // Keep the chunk of an unknown event type for forward compatibility.
+        let chunk_type = match StreamChunkType::from_str(&event.event) {
+            | Ok(chunk_type) => chunk_type,
+            | Err(_) => {
+                return Ok(StreamChunk::Unknown(UnknownChunk::new(
+                    event.event.as_str(),
+                    data,
+                )));
+            },
+        };
 
         // Deserialize the chunk data.
         match chunk_type {
@@ -211,6 +245,11 @@ impl StreamChunk {
                     .map_err(StreamError::ChunkDataDeserializationError)?;
                 Ok(StreamChunk::MessageStop(stop))
             },
+            | StreamChunkType::Error => {
+                let error = serde_json::from_str(data)
+                    .map_err(StreamError::ChunkDataDeserializationError)?;
+                Ok(StreamChunk::Error(error))
+            },
         }
     }
 }
@@ -232,6 +271,8 @@ pub enum StreamChunkType {
     MessageDelta,
     /// message_stop
     MessageStop,
+    /// error
+    Error,
 }
 
 impl Display for StreamChunkType {
@@ -253,6 +294,7 @@ impl Display for StreamChunkType {
             },
             | StreamChunkType::MessageDelta => write!(f, "message_delta"),
             | StreamChunkType::MessageStop => write!(f, "message_stop"),
+            | StreamChunkType::Error => write!(f, "error"),
         }
     }
 }
@@ -269,6 +311,7 @@ impl FromStr for StreamChunkType {
             | "content_block_stop" => Ok(StreamChunkType::ContentBlockStop),
             | "message_delta" => Ok(StreamChunkType::MessageDelta),
             | "message_stop" => Ok(StreamChunkType::MessageStop),
+            | "error" => Ok(StreamChunkType::Error),
             | _ => Err(format!(
                 "Unknown stream chunk type: {}",
                 s
@@ -285,7 +328,8 @@ impl_enum_string_serialization!(
     ContentBlockDelta => "content_block_delta",
     ContentBlockStop => "content_block_stop",
     MessageDelta => "message_delta",
-    MessageStop => "message_stop"
+    MessageStop => "message_stop",
+    Error => "error"
 );
 
 /// The message start chunk.
@@ -327,8 +371,8 @@ pub struct ContentBlockStartChunk {
     pub _type: StreamChunkType,
     /// The index.
     pub index: u32,
-    /// The text content block of start.
-    pub content_block: TextContentBlock,
+    /// The content block of start, e.g. an empty text or a tool use without input.
+    pub content_block: ContentBlock,
 }
 
 impl Default for ContentBlockStartChunk {
@@ -345,14 +389,17 @@ impl_display_for_serialize!(ContentBlockStartChunk);
 
 impl ContentBlockStartChunk {
     /// Creates a new `ContentBlockStart` instance.
-    pub fn new(
+    pub fn new<T>(
         index: u32,
-        content_block: TextContentBlock,
-    ) -> Self {
+        content_block: T,
+    ) -> Self
+    where
+        T: Into<ContentBlock>,
+    {
         Self {
             _type: StreamChunkType::ContentBlockStart,
             index,
-            content_block,
+            content_block: content_block.into(),
         }
     }
 }
@@ -392,8 +439,8 @@ pub struct ContentBlockDeltaChunk {
     pub _type: StreamChunkType,
     /// The index.
     pub index: u32,
-    /// The text delta content block.
-    pub delta: TextDeltaContentBlock,
+    /// The delta content block, e.g. a text delta or an input JSON delta.
+    pub delta: ContentBlock,
 }
 
 impl Default for ContentBlockDeltaChunk {
@@ -401,7 +448,7 @@ impl Default for ContentBlockDeltaChunk {
         Self {
             _type: StreamChunkType::ContentBlockDelta,
             index: Default::default(),
-            delta: Default::default(),
+            delta: ContentBlock::TextDelta(TextDeltaContentBlock::default()),
         }
     }
 }
@@ -410,14 +457,25 @@ impl_display_for_serialize!(ContentBlockDeltaChunk);
 
 impl ContentBlockDeltaChunk {
     /// Creates a new `ContentBlockDelta` instance.
-    pub fn new(
+    pub fn new<T>(
         index: u32,
-        delta: TextDeltaContentBlock,
-    ) -> Self {
+        delta: T,
+    ) -> Self
+    where
+        T: Into<ContentBlock>,
+    {
         Self {
             _type: StreamChunkType::ContentBlockDelta,
             index,
-            delta,
+            delta: delta.into(),
+        }
+    }
+
+    /// Returns the text of the delta if it is a text delta.
+    pub fn text(&self) -> Option<&str> {
+        match &self.delta {
+            | ContentBlock::TextDelta(text_delta) => Some(&text_delta.text),
+            | _ => None,
         }
     }
 }
@@ -518,6 +576,34 @@ impl MessageStopChunk {
     }
 }
 
+/// The chunk of an event type unknown to this crate.
+///
+/// The API may add new event types, so they are passed through instead of failing the stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct UnknownChunk {
+    /// The event type.
+    pub event: String,
+    /// The raw event data.
+    pub data: String,
+}
+
+impl UnknownChunk {
+    /// Creates a new `UnknownChunk` instance.
+    pub fn new<S, T>(
+        event: S,
+        data: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            event: event.into(),
+            data: data.into(),
+        }
+    }
+}
+
 /// The stream stop information.
 #[derive(
     Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
@@ -585,6 +671,10 @@ mod tests {
             StreamChunkType::from_str("message_stop").unwrap(),
             StreamChunkType::MessageStop
         );
+        assert_eq!(
+            StreamChunkType::from_str("error").unwrap(),
+            StreamChunkType::Error
+        );
     }
 
     #[test]
@@ -617,6 +707,10 @@ mod tests {
             StreamChunkType::MessageStop.to_string(),
             "message_stop"
         );
+        assert_eq!(
+            StreamChunkType::Error.to_string(),
+            "error"
+        );
     }
 
     #[test]
@@ -809,6 +903,8 @@ mod tests {
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    cache_creation_input_tokens: None,
+                    cache_read_input_tokens: None,
                 },
             },
         };
@@ -833,6 +929,8 @@ mod tests {
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    cache_creation_input_tokens: None,
+                    cache_read_input_tokens: None,
                 },
             },
         };
@@ -857,6 +955,8 @@ mod tests {
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    cache_creation_input_tokens: None,
+                    cache_read_input_tokens: None,
                 },
             },
         };
@@ -886,10 +986,10 @@ mod tests {
         let content_block_start = ContentBlockStartChunk {
             _type: StreamChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             content_block_start.to_string(),
@@ -902,10 +1002,10 @@ mod tests {
         let content_block_start = ContentBlockStartChunk {
             _type: StreamChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             serde_json::to_string(&content_block_start).unwrap(),
@@ -918,10 +1018,10 @@ mod tests {
         let content_block_start = ContentBlockStartChunk {
             _type: StreamChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             serde_json::from_str::<ContentBlockStartChunk>(
@@ -976,7 +1076,7 @@ mod tests {
             ContentBlockDeltaChunk {
                 _type: StreamChunkType::ContentBlockDelta,
                 index: Default::default(),
-                delta: Default::default(),
+                delta: ContentBlock::TextDelta(Default::default()),
             }
         );
     }
@@ -986,10 +1086,10 @@ mod tests {
         let content_block_delta = ContentBlockDeltaChunk {
             _type: StreamChunkType::ContentBlockDelta,
             index: 1,
-            delta: TextDeltaContentBlock {
+            delta: ContentBlock::TextDelta(TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             content_block_delta.to_string(),
@@ -1002,10 +1102,10 @@ mod tests {
         let content_block_delta = ContentBlockDeltaChunk {
             _type: StreamChunkType::ContentBlockDelta,
             index: 1,
-            delta: TextDeltaContentBlock {
+            delta: ContentBlock::TextDelta(TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             serde_json::to_string(&content_block_delta).unwrap(),
@@ -1018,10 +1118,10 @@ mod tests {
         let content_block_delta = ContentBlockDeltaChunk {
             _type: StreamChunkType::ContentBlockDelta,
             index: 1,
-            delta: TextDeltaContentBlock {
+            delta: ContentBlock::TextDelta(TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             serde_json::from_str::<ContentBlockDeltaChunk>(
@@ -1032,6 +1132,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn text_content_block_delta() {
+        assert_eq!(
+            ContentBlockDeltaChunk::new(0, TextDeltaContentBlock::new("text"))
+                .text(),
+            Some("text")
+        );
+        assert_eq!(
+            ContentBlockDeltaChunk::new(
+                0,
+                InputJsonDeltaContentBlock::new("{}")
+            )
+            .text(),
+            None
+        );
+    }
+
     #[test]
     fn default_content_block_stop() {
         assert_eq!(
@@ -1206,25 +1323,27 @@ mod tests {
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    cache_creation_input_tokens: None,
+                    cache_read_input_tokens: None,
                 },
             },
         };
         let content_block_start = ContentBlockStartChunk {
             _type: StreamChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         let ping = PingChunk::default();
         let content_block_delta = ContentBlockDeltaChunk {
             _type: StreamChunkType::ContentBlockDelta,
             index: 1,
-            delta: TextDeltaContentBlock {
+            delta: ContentBlock::TextDelta(TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         let content_block_stop = ContentBlockStopChunk {
             _type: StreamChunkType::ContentBlockStop,
@@ -1278,6 +1397,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_message_start_with_cache_usage() {
+        match StreamChunk::parse(
+            r#"event: message_start
+data: {"type": "message_start", "message": {"id": "msg_01", "type": "message", "role": "assistant", "content": [], "model": "claude-3-haiku-20240307", "stop_reason": null, "stop_sequence": null, "usage": {"input_tokens": 10, "cache_creation_input_tokens": 0, "cache_read_input_tokens": 2048, "output_tokens": 1}}}"#,
+        )
+        .unwrap()
+        {
+            | StreamChunk::MessageStart(message_start) => {
+                assert_eq!(
+                    message_start.message.usage,
+                    Usage {
+                        input_tokens: 10,
+                        output_tokens: 1,
+                        cache_creation_input_tokens: Some(0),
+                        cache_read_input_tokens: Some(2048),
+                    }
+                );
+            },
+            | _ => panic!("unexpected chunk"),
+        }
+    }
+
     #[test]
     fn parse_stream_chunk() {
         assert_eq!(
@@ -1299,6 +1441,8 @@ data: {"type": "message_start", "message": {"id": "msg_1nZdL29xx5MUA1yADyHTEsnR8
                     usage: Usage {
                         input_tokens: 25,
                         output_tokens: 1,
+                        cache_creation_input_tokens: None,
+                        cache_read_input_tokens: None,
                     },
                 },
             })
@@ -1310,10 +1454,10 @@ data: {"type": "content_block_start", "index": 0, "content_block": {"type": "tex
             StreamChunk::ContentBlockStart(ContentBlockStartChunk {
                 _type: StreamChunkType::ContentBlockStart,
                 index: 0,
-                content_block: TextContentBlock {
+                content_block: ContentBlock::Text(TextContentBlock {
                     text: "".to_string(),
                     ..Default::default()
-                },
+                }),
             })
         );
 
@@ -1335,10 +1479,10 @@ data: {"type": "content_block_delta", "index": 0, "delta": {"type": "text_delta"
             StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk {
                 _type: StreamChunkType::ContentBlockDelta,
                 index: 0,
-                delta: TextDeltaContentBlock {
+                delta: ContentBlock::TextDelta(TextDeltaContentBlock {
                     text: "Hello".to_string(),
                     ..Default::default()
-                },
+                }),
             })
         );
 
@@ -1381,9 +1525,117 @@ data: {"type": "message_stop"}"#
             StreamChunk::MessageStop(MessageStopChunk::default())
         );
 
+        assert_eq!(
+            StreamChunk::parse(
+                r#"event: content_block_start
+data: {"type": "content_block_start", "index": 1, "content_block": {"type": "tool_use", "id": "toolu_01", "name": "get_weather", "input": {}}}"#
+            )
+            .unwrap(),
+            StreamChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                1,
+                ToolUseContentBlock::new(
+                    "toolu_01",
+                    "get_weather",
+                    serde_json::json!({}),
+                ),
+            ))
+        );
+
+        assert_eq!(
+            StreamChunk::parse(
+                r#"event: content_block_delta
+data: {"type": "content_block_delta", "index": 1, "delta": {"type": "input_json_delta", "partial_json": "{\"location\": \"Tok"}}"#
+            )
+            .unwrap(),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                1,
+                InputJsonDeltaContentBlock::new("{\"location\": \"Tok"),
+            ))
+        );
+
+        assert_eq!(
+            StreamChunk::parse(
+                r#"event: content_block_delta
+data: {"type": "content_block_delta", "index": 0, "delta": {"type": "citations_delta", "citation": {"type": "char_location", "cited_text": "The grass is green.", "document_index": 0, "document_title": "Facts", "start_char_index": 0, "end_char_index": 20}}}"#
+            )
+            .unwrap(),
+            StreamChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                CitationsDeltaContentBlock::new(Citation::CharLocation(
+                    CharLocationCitation {
+                        cited_text: "The grass is green.".to_string(),
+                        document_title: Some("Facts".to_string()),
+                        end_char_index: 20,
+                        ..Default::default()
+                    }
+                )),
+            ))
+        );
+
+        assert_eq!(
+            StreamChunk::parse(
+                r#"event: error
+data: {"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}"#
+            )
+            .unwrap(),
+            StreamChunk::Error(ApiErrorResponse {
+                _type: "error".to_string(),
+                error: crate::ApiErrorBody {
+                    _type: "overloaded_error".to_string(),
+                    message: "Overloaded".to_string(),
+                },
+            })
+        );
+
+        assert_eq!(
+            StreamChunk::parse("event: unknown\ndata: {}").unwrap(),
+            StreamChunk::Unknown(UnknownChunk::new("unknown", "{}"))
+        );
+
         assert!(matches!(
-            StreamChunk::parse("event: unknown\ndata: {}"),
+            StreamChunk::parse("event: ping"),
             Err(StreamError::ParseChunkStringError(_))
         ));
     }
+
+    #[test]
+    fn display_error_and_unknown_chunk() {
+        let error = ApiErrorResponse {
+            _type: "error".to_string(),
+            error: crate::ApiErrorBody {
+                _type: "overloaded_error".to_string(),
+                message: "Overloaded".to_string(),
+            },
+        };
+        assert_eq!(
+            StreamChunk::Error(error).to_string(),
+            "event: error\ndata: {\"type\": \"error\", \"error\": {\"type\": \"overloaded_error\", \"message\": \"Overloaded\"}}"
+        );
+
+        assert_eq!(
+            StreamChunk::Unknown(UnknownChunk::new("unknown", "{}")).to_string(),
+            "event: unknown\ndata: {}"
+        );
+    }
+
+    #[test]
+    fn stream_error_from_error_chunk() {
+        let error = ApiErrorResponse {
+            _type: "error".to_string(),
+            error: crate::ApiErrorBody {
+                _type: "overloaded_error".to_string(),
+                message: "Overloaded".to_string(),
+            },
+        };
+        match StreamError::from(error.clone()) {
+            | StreamError::ApiError {
+                _type,
+                response,
+            } => {
+                assert_eq!(_type, crate::ApiErrorType::OverloadedError);
+                assert_eq!(response, error);
+            },
+            | _ => panic!("unexpected error"),
+        }
+    }
 }
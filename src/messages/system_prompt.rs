@@ -1,20 +1,24 @@
+use crate::macros::impl_enum_with_string_or_array_serialization;
+use crate::messages::{CacheControl, TextContentBlock};
 use std::fmt::Display;
 
 /// System prompt.
 ///
 /// A system prompt is a way of providing context and instructions to Claude, such as specifying a particular goal or role.
 /// See our [guide to system prompts](https://docs.anthropic.com/claude/docs/system-prompts).
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-#[serde(transparent)]
-pub struct SystemPrompt {
-    value: String,
+///
+/// It is either a single text or multiple text blocks, e.g. to mark a prompt caching breakpoint by [`TextContentBlock::with_cache_control`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SystemPrompt {
+    /// The single text system prompt.
+    SingleText(String),
+    /// The multiple text blocks system prompt.
+    MultipleBlock(Vec<TextContentBlock>),
 }
 
 impl Default for SystemPrompt {
     fn default() -> Self {
-        Self {
-            value: String::new(),
-        }
+        Self::SingleText(String::new())
     }
 }
 
@@ -23,36 +27,139 @@ impl Display for SystemPrompt {
         &self,
         f: &mut std::fmt::Formatter<'_>,
     ) -> std::fmt::Result {
-        write!(f, "{}", self.value)
-    }
-}
-
-impl From<String> for SystemPrompt {
-    fn from(value: String) -> Self {
-        Self {
-            value,
+        match self {
+            | SystemPrompt::SingleText(text) => {
+                write!(f, "{}", text)
+            },
+            | SystemPrompt::MultipleBlock(blocks) => {
+                let texts: Vec<&str> = blocks
+                    .iter()
+                    .map(|block| block.text.as_str())
+                    .collect();
+                write!(f, "{}", texts.join("\n"))
+            },
         }
     }
 }
 
 impl From<&str> for SystemPrompt {
     fn from(value: &str) -> Self {
-        Self {
-            value: value.to_string(),
-        }
+        Self::SingleText(value.to_string())
     }
 }
 
+impl_enum_with_string_or_array_serialization!(
+    SystemPrompt,
+    SingleText(String),
+    MultipleBlock(TextContentBlock)
+);
+
 impl SystemPrompt {
-    /// Creates a new system prompt.
+    /// Creates a new system prompt of a single text.
     pub fn new<S>(value: S) -> Self
     where
         S: Into<String>,
     {
+        Self::SingleText(value.into())
+    }
+
+    /// Creates a builder to compose a system prompt of multiple text blocks.
+    pub fn builder() -> SystemPromptBuilder {
+        SystemPromptBuilder::new()
+    }
+}
+
+/// The builder to compose a [`SystemPrompt`] from text blocks, e.g. named sections of persona, rules and context.
+///
+/// ## Example
+/// ```
+/// use clust::messages::{CacheControl, SystemPrompt};
+///
+/// let system_prompt = SystemPrompt::builder()
+///     .text("You are an excellent AI assistant.")
+///     .section("rules", "Answer in English.")
+///     .section("context", "A long document.")
+///     .cache_control(CacheControl::ephemeral())
+///     .build();
+///
+/// assert_eq!(
+///     system_prompt.to_string(),
+///     "You are an excellent AI assistant.\n<rules>\nAnswer in English.\n</rules>\n<context>\nA long document.\n</context>"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SystemPromptBuilder {
+    /// The text blocks of the system prompt.
+    blocks: Vec<TextContentBlock>,
+}
+
+impl SystemPromptBuilder {
+    /// Creates a new empty builder.
+    pub fn new() -> Self {
         Self {
-            value: value.into(),
+            blocks: Vec::new(),
         }
     }
+
+    /// Appends a text block as is.
+    ///
+    /// ## Arguments
+    /// - `text` - The text of the block.
+    pub fn text<S>(
+        mut self,
+        text: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.blocks
+            .push(TextContentBlock::new(text));
+        self
+    }
+
+    /// Appends a named section as a text block enclosed in the XML tag of the name, e.g. `<rules>...</rules>`.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the section.
+    /// - `text` - The text of the section.
+    pub fn section<N, S>(
+        self,
+        name: N,
+        text: S,
+    ) -> Self
+    where
+        N: AsRef<str>,
+        S: AsRef<str>,
+    {
+        let name = name.as_ref();
+        self.text(format!(
+            "<{}>\n{}\n</{}>",
+            name,
+            text.as_ref(),
+            name
+        ))
+    }
+
+    /// Sets the cache control on the last block to mark a prompt caching breakpoint.
+    ///
+    /// It has no effect if there are no blocks.
+    ///
+    /// ## Arguments
+    /// - `cache_control` - The cache control.
+    pub fn cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        if let Some(block) = self.blocks.last_mut() {
+            block.cache_control = Some(cache_control);
+        }
+        self
+    }
+
+    /// Builds the system prompt of multiple text blocks.
+    pub fn build(self) -> SystemPrompt {
+        SystemPrompt::MultipleBlock(self.blocks)
+    }
 }
 
 #[cfg(test)]
@@ -62,12 +169,18 @@ mod tests {
     #[test]
     fn new() {
         let system_prompt = SystemPrompt::new("system-prompt");
-        assert_eq!(system_prompt.value, "system-prompt");
+        assert_eq!(
+            system_prompt,
+            SystemPrompt::SingleText("system-prompt".to_string())
+        );
     }
 
     #[test]
     fn default() {
-        assert_eq!(SystemPrompt::default().value, "");
+        assert_eq!(
+            SystemPrompt::default(),
+            SystemPrompt::SingleText(String::new())
+        );
     }
 
     #[test]
@@ -77,6 +190,15 @@ mod tests {
             system_prompt.to_string(),
             "system-prompt"
         );
+
+        let system_prompt = SystemPrompt::from(vec![
+            TextContentBlock::new("persona"),
+            TextContentBlock::new("rules"),
+        ]);
+        assert_eq!(
+            system_prompt.to_string(),
+            "persona\nrules"
+        );
     }
 
     #[test]
@@ -86,6 +208,16 @@ mod tests {
             serde_json::to_string(&system_prompt).unwrap(),
             "\"system-prompt\""
         );
+
+        let system_prompt = SystemPrompt::from(vec![
+            TextContentBlock::new("instructions"),
+            TextContentBlock::new("long document")
+                .with_cache_control(CacheControl::ephemeral()),
+        ]);
+        assert_eq!(
+            serde_json::to_string(&system_prompt).unwrap(),
+            r#"[{"type":"text","text":"instructions"},{"type":"text","text":"long document","cache_control":{"type":"ephemeral"}}]"#
+        );
     }
 
     #[test]
@@ -95,5 +227,42 @@ mod tests {
             serde_json::from_str::<SystemPrompt>("\"system-prompt\"").unwrap(),
             system_prompt
         );
+
+        assert_eq!(
+            serde_json::from_str::<SystemPrompt>(
+                r#"[{"type":"text","text":"instructions","cache_control":{"type":"ephemeral"}}]"#
+            )
+            .unwrap(),
+            SystemPrompt::MultipleBlock(vec![TextContentBlock::new(
+                "instructions"
+            )
+            .with_cache_control(CacheControl::ephemeral())])
+        );
+    }
+
+    #[test]
+    fn build() {
+        let system_prompt = SystemPrompt::builder()
+            .text("persona")
+            .section("rules", "rule")
+            .cache_control(CacheControl::ephemeral())
+            .section("context", "context")
+            .build();
+        assert_eq!(
+            system_prompt,
+            SystemPrompt::MultipleBlock(vec![
+                TextContentBlock::new("persona"),
+                TextContentBlock::new("<rules>\nrule\n</rules>")
+                    .with_cache_control(CacheControl::ephemeral()),
+                TextContentBlock::new("<context>\ncontext\n</context>"),
+            ])
+        );
+
+        assert_eq!(
+            SystemPromptBuilder::new()
+                .cache_control(CacheControl::ephemeral())
+                .build(),
+            SystemPrompt::MultipleBlock(vec![])
+        );
     }
 }
@@ -46,7 +46,7 @@ impl Temperature {
     /// ## Errors
     /// It returns a validation error if the value is not in range: `[0.0, 1.0]`.
     pub fn new(value: f32) -> ValidationResult<Self, f32> {
-        if value < 0.0 || value > 1.0 {
+        if !(0.0..=1.0).contains(&value) {
             return Err(ValidationError {
                 _type: "Temperature".to_string(),
                 expected: "The temperature must be in range: [0.0, 1.0]."
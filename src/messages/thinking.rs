@@ -0,0 +1,245 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+    impl_enum_struct_serialization,
+};
+use crate::messages::MaxTokens;
+use crate::{ValidationError, ValidationResult};
+use std::fmt::Display;
+
+/// The configuration of extended thinking.
+///
+/// When enabled, the response includes `thinking` content blocks of the reasoning before the final answer.
+///
+/// See also [extended thinking](https://docs.anthropic.com/en/docs/build-with-claude/extended-thinking).
+///
+/// ## Example
+/// ```
+/// use clust::messages::{ClaudeModel, MaxTokens, ThinkingConfig};
+///
+/// // A model supporting extended thinking, which is unknown to this crate.
+/// let model = ClaudeModel::new("claude-3-7-sonnet-20250219");
+/// let max_tokens = MaxTokens::new(4096, &model).unwrap();
+/// let thinking = ThinkingConfig::enabled(2048, &max_tokens).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThinkingConfig {
+    /// Extended thinking is enabled with the budget.
+    Enabled(EnabledThinkingConfig),
+    /// Extended thinking is disabled.
+    Disabled(DisabledThinkingConfig),
+}
+
+impl Default for ThinkingConfig {
+    fn default() -> Self {
+        Self::Disabled(DisabledThinkingConfig::default())
+    }
+}
+
+impl_enum_struct_serialization!(
+    ThinkingConfig,
+    type,
+    Enabled(EnabledThinkingConfig, "enabled"),
+    Disabled(DisabledThinkingConfig, "disabled")
+);
+
+impl_display_for_serialize!(ThinkingConfig);
+
+impl ThinkingConfig {
+    /// The minimum number of budget tokens.
+    pub const MIN_BUDGET_TOKENS: u32 = 1024;
+
+    /// Creates a configuration that enables extended thinking.
+    ///
+    /// ## Arguments
+    /// - `budget_tokens` - The maximum number of tokens to use for the internal reasoning.
+    /// - `max_tokens` - The maximum number of tokens of the request.
+    ///
+    /// ## Errors
+    /// It returns a validation error if the budget is less than 1024 or not less than the maximum number of tokens.
+    pub fn enabled(
+        budget_tokens: u32,
+        max_tokens: &MaxTokens,
+    ) -> ValidationResult<Self, u32> {
+        Self::validate_budget_tokens(budget_tokens, max_tokens)?;
+
+        Ok(Self::Enabled(EnabledThinkingConfig {
+            _type: ThinkingConfigType::Enabled,
+            budget_tokens,
+        }))
+    }
+
+    /// Creates a configuration that disables extended thinking.
+    pub fn disabled() -> Self {
+        Self::Disabled(DisabledThinkingConfig::default())
+    }
+
+    /// Returns the budget tokens if extended thinking is enabled.
+    pub fn budget_tokens(&self) -> Option<u32> {
+        match self {
+            | ThinkingConfig::Enabled(enabled) => Some(enabled.budget_tokens),
+            | ThinkingConfig::Disabled(_) => None,
+        }
+    }
+
+    /// Validates the budget tokens against the maximum number of tokens.
+    pub(crate) fn validate_budget_tokens(
+        budget_tokens: u32,
+        max_tokens: &MaxTokens,
+    ) -> ValidationResult<(), u32> {
+        if budget_tokens < Self::MIN_BUDGET_TOKENS {
+            return Err(ValidationError {
+                _type: "ThinkingConfig".to_string(),
+                expected: format!(
+                    "The budget tokens must be at least {}.",
+                    Self::MIN_BUDGET_TOKENS
+                ),
+                actual: budget_tokens,
+            });
+        }
+
+        if budget_tokens >= max_tokens.value() {
+            return Err(ValidationError {
+                _type: "ThinkingConfig".to_string(),
+                expected: format!(
+                    "The budget tokens must be less than the maximum number of tokens: {}.",
+                    max_tokens
+                ),
+                actual: budget_tokens,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The configuration that enables extended thinking.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EnabledThinkingConfig {
+    /// The configuration type. It is always `enabled`.
+    #[serde(rename = "type")]
+    pub _type: ThinkingConfigType,
+    /// The maximum number of tokens to use for the internal reasoning.
+    ///
+    /// It must be at least 1024 and less than the maximum number of tokens.
+    pub budget_tokens: u32,
+}
+
+impl Default for EnabledThinkingConfig {
+    fn default() -> Self {
+        Self {
+            _type: ThinkingConfigType::Enabled,
+            budget_tokens: ThinkingConfig::MIN_BUDGET_TOKENS,
+        }
+    }
+}
+
+impl_display_for_serialize!(EnabledThinkingConfig);
+
+/// The configuration that disables extended thinking.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DisabledThinkingConfig {
+    /// The configuration type. It is always `disabled`.
+    #[serde(rename = "type")]
+    pub _type: ThinkingConfigType,
+}
+
+impl Default for DisabledThinkingConfig {
+    fn default() -> Self {
+        Self {
+            _type: ThinkingConfigType::Disabled,
+        }
+    }
+}
+
+impl_display_for_serialize!(DisabledThinkingConfig);
+
+/// The type of the extended thinking configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThinkingConfigType {
+    /// enabled
+    Enabled,
+    /// disabled
+    Disabled,
+}
+
+impl Default for ThinkingConfigType {
+    fn default() -> Self {
+        Self::Disabled
+    }
+}
+
+impl Display for ThinkingConfigType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ThinkingConfigType::Enabled => {
+                write!(f, "enabled")
+            },
+            | ThinkingConfigType::Disabled => {
+                write!(f, "disabled")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ThinkingConfigType,
+    Enabled => "enabled",
+    Disabled => "disabled"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ClaudeModel;
+
+    fn max_tokens(value: u32) -> MaxTokens {
+        MaxTokens::new(value, &ClaudeModel::Claude3Haiku20240307).unwrap()
+    }
+
+    #[test]
+    fn enabled() {
+        let thinking = ThinkingConfig::enabled(1024, &max_tokens(2048)).unwrap();
+        assert_eq!(thinking.budget_tokens(), Some(1024));
+
+        assert!(ThinkingConfig::enabled(1023, &max_tokens(2048)).is_err());
+        assert!(ThinkingConfig::enabled(2048, &max_tokens(2048)).is_err());
+        assert_eq!(
+            ThinkingConfig::disabled().budget_tokens(),
+            None
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(
+                &ThinkingConfig::enabled(2048, &max_tokens(4096)).unwrap()
+            )
+            .unwrap(),
+            r#"{"type":"enabled","budget_tokens":2048}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ThinkingConfig::disabled()).unwrap(),
+            r#"{"type":"disabled"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<ThinkingConfig>(
+                r#"{"type":"enabled","budget_tokens":2048}"#
+            )
+            .unwrap(),
+            ThinkingConfig::enabled(2048, &max_tokens(4096)).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_str::<ThinkingConfig>(r#"{"type":"disabled"}"#)
+                .unwrap(),
+            ThinkingConfig::disabled()
+        );
+    }
+}
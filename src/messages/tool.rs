@@ -0,0 +1,365 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+    impl_enum_struct_serialization,
+};
+use crate::messages::CacheControl;
+use std::fmt::Display;
+
+/// The definition of a tool that the model may use.
+///
+/// See also [tool use](https://docs.anthropic.com/claude/docs/tool-use).
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct ToolDefinition {
+    /// The name of the tool.
+    ///
+    /// It must match the regex `^[a-zA-Z0-9_-]{1,64}$`.
+    pub name: String,
+    /// The description of the tool.
+    ///
+    /// A detailed description helps the model to decide when and how to use the tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The [JSON schema](https://json-schema.org/) for the input of the tool.
+    pub input_schema: serde_json::Value,
+    /// The cache control to mark a prompt caching breakpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl_display_for_serialize!(ToolDefinition);
+
+impl ToolDefinition {
+    /// Creates a new tool definition.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the tool.
+    /// - `description` - The description of the tool.
+    /// - `input_schema` - The JSON schema for the input of the tool.
+    pub fn new<S>(
+        name: S,
+        description: Option<String>,
+        input_schema: serde_json::Value,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            description,
+            input_schema,
+            cache_control: None,
+        }
+    }
+
+    /// Sets the cache control to mark a prompt caching breakpoint at this tool.
+    ///
+    /// ## Arguments
+    /// - `cache_control` - The cache control.
+    pub fn with_cache_control(
+        mut self,
+        cache_control: CacheControl,
+    ) -> Self {
+        self.cache_control = Some(cache_control);
+        self
+    }
+}
+
+/// How the model should use the provided tools.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolChoice {
+    /// The model decides whether to use any tool or not.
+    Auto(AutoToolChoice),
+    /// The model must use one of the provided tools.
+    Any(AnyToolChoice),
+    /// The model must use the specified tool.
+    Tool(SpecificToolChoice),
+}
+
+impl Default for ToolChoice {
+    fn default() -> Self {
+        Self::Auto(AutoToolChoice::default())
+    }
+}
+
+impl_enum_struct_serialization!(
+    ToolChoice,
+    type,
+    Auto(AutoToolChoice, "auto"),
+    Any(AnyToolChoice, "any"),
+    Tool(SpecificToolChoice, "tool")
+);
+
+impl_display_for_serialize!(ToolChoice);
+
+impl ToolChoice {
+    /// Creates a tool choice that lets the model decide whether to use any tool or not.
+    pub fn auto() -> Self {
+        Self::Auto(AutoToolChoice::default())
+    }
+
+    /// Creates a tool choice that forces the model to use one of the provided tools.
+    pub fn any() -> Self {
+        Self::Any(AnyToolChoice::default())
+    }
+
+    /// Creates a tool choice that forces the model to use the specified tool.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the tool.
+    pub fn tool<S>(name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Tool(SpecificToolChoice::new(name))
+    }
+}
+
+/// The tool choice that lets the model decide whether to use any tool or not.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AutoToolChoice {
+    /// The tool choice type. It is always `auto`.
+    #[serde(rename = "type")]
+    pub _type: ToolChoiceType,
+}
+
+impl Default for AutoToolChoice {
+    fn default() -> Self {
+        Self {
+            _type: ToolChoiceType::Auto,
+        }
+    }
+}
+
+impl_display_for_serialize!(AutoToolChoice);
+
+/// The tool choice that forces the model to use one of the provided tools.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AnyToolChoice {
+    /// The tool choice type. It is always `any`.
+    #[serde(rename = "type")]
+    pub _type: ToolChoiceType,
+}
+
+impl Default for AnyToolChoice {
+    fn default() -> Self {
+        Self {
+            _type: ToolChoiceType::Any,
+        }
+    }
+}
+
+impl_display_for_serialize!(AnyToolChoice);
+
+/// The tool choice that forces the model to use the specified tool.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SpecificToolChoice {
+    /// The tool choice type. It is always `tool`.
+    #[serde(rename = "type")]
+    pub _type: ToolChoiceType,
+    /// The name of the tool to use.
+    pub name: String,
+}
+
+impl Default for SpecificToolChoice {
+    fn default() -> Self {
+        Self {
+            _type: ToolChoiceType::Tool,
+            name: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(SpecificToolChoice);
+
+impl SpecificToolChoice {
+    /// Creates a new specific tool choice.
+    pub fn new<S>(name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ToolChoiceType::Tool,
+            name: name.into(),
+        }
+    }
+}
+
+/// The type of tool choice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToolChoiceType {
+    /// auto
+    Auto,
+    /// any
+    Any,
+    /// tool
+    Tool,
+}
+
+impl Default for ToolChoiceType {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl Display for ToolChoiceType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ToolChoiceType::Auto => {
+                write!(f, "auto")
+            },
+            | ToolChoiceType::Any => {
+                write!(f, "any")
+            },
+            | ToolChoiceType::Tool => {
+                write!(f, "tool")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ToolChoiceType,
+    Auto => "auto",
+    Any => "any",
+    Tool => "tool"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_tool_definition() {
+        let tool_definition = ToolDefinition::new(
+            "get_weather",
+            Some("Get the current weather.".to_string()),
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "location": {
+                        "type": "string"
+                    }
+                },
+                "required": ["location"]
+            }),
+        );
+        assert_eq!(tool_definition.name, "get_weather");
+        assert_eq!(
+            tool_definition.description,
+            Some("Get the current weather.".to_string())
+        );
+    }
+
+    #[test]
+    fn serialize_tool_definition() {
+        let tool_definition = ToolDefinition::new(
+            "get_weather",
+            Some("Get the current weather.".to_string()),
+            serde_json::json!({
+                "type": "object",
+            }),
+        );
+        assert_eq!(
+            serde_json::to_string(&tool_definition).unwrap(),
+            "{\"name\":\"get_weather\",\"description\":\"Get the current weather.\",\"input_schema\":{\"type\":\"object\"}}"
+        );
+
+        let tool_definition = ToolDefinition::new(
+            "get_weather",
+            None,
+            serde_json::json!({
+                "type": "object",
+            }),
+        );
+        assert_eq!(
+            serde_json::to_string(&tool_definition).unwrap(),
+            "{\"name\":\"get_weather\",\"input_schema\":{\"type\":\"object\"}}"
+        );
+
+        let tool_definition = ToolDefinition::new(
+            "get_weather",
+            None,
+            serde_json::json!({
+                "type": "object",
+            }),
+        )
+        .with_cache_control(CacheControl::ephemeral());
+        assert_eq!(
+            serde_json::to_string(&tool_definition).unwrap(),
+            "{\"name\":\"get_weather\",\"input_schema\":{\"type\":\"object\"},\"cache_control\":{\"type\":\"ephemeral\"}}"
+        );
+    }
+
+    #[test]
+    fn deserialize_tool_definition() {
+        let tool_definition = ToolDefinition::new(
+            "get_weather",
+            Some("Get the current weather.".to_string()),
+            serde_json::json!({
+                "type": "object",
+            }),
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolDefinition>(
+                "{\"name\":\"get_weather\",\"description\":\"Get the current weather.\",\"input_schema\":{\"type\":\"object\"}}"
+            )
+            .unwrap(),
+            tool_definition
+        );
+    }
+
+    #[test]
+    fn default_tool_choice() {
+        assert_eq!(
+            ToolChoice::default(),
+            ToolChoice::auto()
+        );
+    }
+
+    #[test]
+    fn display_tool_choice_type() {
+        assert_eq!(ToolChoiceType::Auto.to_string(), "auto");
+        assert_eq!(ToolChoiceType::Any.to_string(), "any");
+        assert_eq!(ToolChoiceType::Tool.to_string(), "tool");
+    }
+
+    #[test]
+    fn serialize_tool_choice() {
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::auto()).unwrap(),
+            "{\"type\":\"auto\"}"
+        );
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::any()).unwrap(),
+            "{\"type\":\"any\"}"
+        );
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::tool("get_weather")).unwrap(),
+            "{\"type\":\"tool\",\"name\":\"get_weather\"}"
+        );
+    }
+
+    #[test]
+    fn deserialize_tool_choice() {
+        assert_eq!(
+            serde_json::from_str::<ToolChoice>("{\"type\":\"auto\"}").unwrap(),
+            ToolChoice::auto()
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolChoice>("{\"type\":\"any\"}").unwrap(),
+            ToolChoice::any()
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolChoice>(
+                "{\"type\":\"tool\",\"name\":\"get_weather\"}"
+            )
+            .unwrap(),
+            ToolChoice::tool("get_weather")
+        );
+    }
+}
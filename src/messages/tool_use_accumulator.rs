@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+
+use crate::messages::{
+    ContentBlock, StreamChunk, StreamError, ToolUseContentBlock,
+};
+
+/// Accumulates streamed tool use content blocks.
+///
+/// The input of a tool use is streamed as fragments of `partial_json` in `input_json_delta` content blocks.
+/// This accumulator buffers them per content block index and yields the tool use with the parsed input when the `content_block_stop` chunk arrives.
+///
+/// ## Example
+/// ```no_run
+/// use clust::messages::ToolUseAccumulator;
+/// use futures_util::StreamExt;
+///
+/// # async fn example(mut stream: impl futures_core::Stream<Item = clust::messages::ChunkStreamResult> + Unpin) -> anyhow::Result<()> {
+/// let mut accumulator = ToolUseAccumulator::new();
+/// while let Some(chunk) = stream.next().await {
+///     if let Some(tool_use) = accumulator.push(&chunk?)? {
+///         println!("Tool use: {}", tool_use);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ToolUseAccumulator {
+    /// The pending tool uses and their partial JSON inputs by index.
+    pending: BTreeMap<u32, (ToolUseContentBlock, String)>,
+}
+
+impl ToolUseAccumulator {
+    /// Creates a new tool use accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes a stream chunk to the accumulator.
+    ///
+    /// ## Arguments
+    /// - `chunk` - The stream chunk.
+    ///
+    /// ## Returns
+    /// The completed tool use with the parsed input when the chunk stops a tool use content block, otherwise `None`.
+    ///
+    /// ## Errors
+    /// It returns an error if an input JSON delta arrives for an unknown index or the accumulated input is not valid JSON.
+    pub fn push(
+        &mut self,
+        chunk: &StreamChunk,
+    ) -> Result<Option<ToolUseContentBlock>, StreamError> {
+        match chunk {
+            | StreamChunk::ContentBlockStart(content_block_start) => {
+                if let ContentBlock::ToolUse(tool_use) =
+                    &content_block_start.content_block
+                {
+                    self.pending.insert(
+                        content_block_start.index,
+                        (tool_use.clone(), String::new()),
+                    );
+                }
+                Ok(None)
+            },
+            | StreamChunk::ContentBlockDelta(content_block_delta) => {
+                if let ContentBlock::InputJsonDelta(input_json_delta) =
+                    &content_block_delta.delta
+                {
+                    let (_, partial_json) = self
+                        .pending
+                        .get_mut(&content_block_delta.index)
+                        .ok_or_else(|| {
+                            StreamError::UnexpectedChunk(format!(
+                                "Input JSON delta for unknown tool use index: {}",
+                                content_block_delta.index
+                            ))
+                        })?;
+                    partial_json.push_str(&input_json_delta.partial_json);
+                }
+                Ok(None)
+            },
+            | StreamChunk::ContentBlockStop(content_block_stop) => {
+                match self
+                    .pending
+                    .remove(&content_block_stop.index)
+                {
+                    | Some((mut tool_use, partial_json)) => {
+                        // A tool without any input streams no fragment, so keep the initial input.
+                        if !partial_json
+                            .trim()
+                            .is_empty()
+                        {
+                            tool_use.input = serde_json::from_str(
+                                &partial_json,
+                            )
+                            .map_err(
+                                StreamError::ChunkDataDeserializationError,
+                            )?;
+                        }
+                        Ok(Some(tool_use))
+                    },
+                    | None => Ok(None),
+                }
+            },
+            | _ => Ok(None),
+        }
+    }
+
+    /// Returns the partial JSON input accumulated so far for the index.
+    ///
+    /// ## Arguments
+    /// - `index` - The index of the content block.
+    pub fn partial_json(
+        &self,
+        index: u32,
+    ) -> Option<&str> {
+        self.pending
+            .get(&index)
+            .map(|(_, partial_json)| partial_json.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        ContentBlockDeltaChunk, ContentBlockStartChunk, ContentBlockStopChunk,
+        InputJsonDeltaContentBlock, TextContentBlock, TextDeltaContentBlock,
+    };
+
+    #[test]
+    fn push() {
+        let mut accumulator = ToolUseAccumulator::new();
+
+        assert_eq!(
+            accumulator
+                .push(&StreamChunk::ContentBlockStart(
+                    ContentBlockStartChunk::new(0, TextContentBlock::new(""))
+                ))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            accumulator
+                .push(&StreamChunk::ContentBlockDelta(
+                    ContentBlockDeltaChunk::new(
+                        0,
+                        TextDeltaContentBlock::new("Hello")
+                    )
+                ))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            accumulator
+                .push(&StreamChunk::ContentBlockStop(
+                    ContentBlockStopChunk::new(0)
+                ))
+                .unwrap(),
+            None
+        );
+
+        assert_eq!(
+            accumulator
+                .push(&StreamChunk::ContentBlockStart(
+                    ContentBlockStartChunk::new(
+                        1,
+                        ToolUseContentBlock::new(
+                            "toolu_01",
+                            "get_weather",
+                            serde_json::json!({}),
+                        )
+                    )
+                ))
+                .unwrap(),
+            None
+        );
+        for fragment in ["", "{\"location\": ", "\"Tok", "yo\"}"] {
+            assert_eq!(
+                accumulator
+                    .push(&StreamChunk::ContentBlockDelta(
+                        ContentBlockDeltaChunk::new(
+                            1,
+                            InputJsonDeltaContentBlock::new(fragment)
+                        )
+                    ))
+                    .unwrap(),
+                None
+            );
+        }
+        assert_eq!(
+            accumulator.partial_json(1),
+            Some("{\"location\": \"Tokyo\"}")
+        );
+        assert_eq!(
+            accumulator
+                .push(&StreamChunk::ContentBlockStop(
+                    ContentBlockStopChunk::new(1)
+                ))
+                .unwrap(),
+            Some(ToolUseContentBlock::new(
+                "toolu_01",
+                "get_weather",
+                serde_json::json!({"location": "Tokyo"}),
+            ))
+        );
+        assert_eq!(accumulator.partial_json(1), None);
+    }
+
+    #[test]
+    fn push_without_input() {
+        let mut accumulator = ToolUseAccumulator::new();
+
+        accumulator
+            .push(&StreamChunk::ContentBlockStart(
+                ContentBlockStartChunk::new(
+                    0,
+                    ToolUseContentBlock::new(
+                        "toolu_01",
+                        "get_time",
+                        serde_json::json!({}),
+                    ),
+                ),
+            ))
+            .unwrap();
+        accumulator
+            .push(&StreamChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(
+                    0,
+                    InputJsonDeltaContentBlock::new(""),
+                ),
+            ))
+            .unwrap();
+        assert_eq!(
+            accumulator
+                .push(&StreamChunk::ContentBlockStop(
+                    ContentBlockStopChunk::new(0)
+                ))
+                .unwrap(),
+            Some(ToolUseContentBlock::new(
+                "toolu_01",
+                "get_time",
+                serde_json::json!({}),
+            ))
+        );
+    }
+
+    #[test]
+    fn push_invalid() {
+        let mut accumulator = ToolUseAccumulator::new();
+
+        assert!(accumulator
+            .push(&StreamChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(
+                    0,
+                    InputJsonDeltaContentBlock::new("{")
+                )
+            ))
+            .is_err());
+
+        accumulator
+            .push(&StreamChunk::ContentBlockStart(
+                ContentBlockStartChunk::new(
+                    0,
+                    ToolUseContentBlock::new(
+                        "toolu_01",
+                        "get_weather",
+                        serde_json::json!({}),
+                    ),
+                ),
+            ))
+            .unwrap();
+        accumulator
+            .push(&StreamChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(
+                    0,
+                    InputJsonDeltaContentBlock::new("{\"location\": "),
+                ),
+            ))
+            .unwrap();
+        assert!(accumulator
+            .push(&StreamChunk::ContentBlockStop(
+                ContentBlockStopChunk::new(0)
+            ))
+            .is_err());
+    }
+}
@@ -23,6 +23,16 @@ pub struct Usage {
     pub input_tokens: u32,
     /// The number of output tokens which were used.
     pub output_tokens: u32,
+    /// The number of input tokens used to create the cache entry.
+    ///
+    /// See [prompt caching](https://docs.anthropic.com/en/docs/build-with-claude/prompt-caching).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_creation_input_tokens: Option<u32>,
+    /// The number of input tokens read from the cache.
+    ///
+    /// See [prompt caching](https://docs.anthropic.com/en/docs/build-with-claude/prompt-caching).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_read_input_tokens: Option<u32>,
 }
 
 impl_display_for_serialize!(Usage);
@@ -36,6 +46,8 @@ mod tests {
         let usage = Usage {
             input_tokens: 1,
             output_tokens: 2,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
         };
         assert_eq!(
             serde_json::to_string(&usage).unwrap(),
@@ -48,6 +60,8 @@ mod tests {
         let usage = Usage {
             input_tokens: 1,
             output_tokens: 2,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: None,
         };
         assert_eq!(
             serde_json::from_str::<Usage>(
@@ -57,4 +71,20 @@ mod tests {
             usage
         );
     }
+
+    #[test]
+    fn deserialize_with_cache() {
+        assert_eq!(
+            serde_json::from_str::<Usage>(
+                r#"{"input_tokens":1,"output_tokens":2,"cache_creation_input_tokens":3,"cache_read_input_tokens":null}"#
+            )
+            .unwrap(),
+            Usage {
+                input_tokens: 1,
+                output_tokens: 2,
+                cache_creation_input_tokens: Some(3),
+                cache_read_input_tokens: None,
+            }
+        );
+    }
 }
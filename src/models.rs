@@ -0,0 +1,12 @@
+//! The [Models API](https://docs.anthropic.com/en/api/models-list) implementations.
+
+mod error;
+mod model_info;
+mod result;
+
+pub(crate) mod api;
+
+pub use error::ModelsError;
+pub use model_info::ModelInfo;
+pub use model_info::ModelObjectType;
+pub use result::ModelsResult;
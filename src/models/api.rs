@@ -0,0 +1,36 @@
+use crate::base_url::encode_path_segment;
+use crate::models::{ModelInfo, ModelsResult};
+use crate::response::read_response;
+use crate::Client;
+use crate::{Page, PaginationQuery};
+
+pub(crate) async fn list_models(
+    client: &Client,
+    query: PaginationQuery,
+) -> ModelsResult<Page<ModelInfo>> {
+    // Send the request.
+    let response = client
+        .send(
+            client
+                .get("/v1/models")
+                .query(&query),
+        )
+        .await?;
+
+    read_response(response).await
+}
+
+pub(crate) async fn get_model(
+    client: &Client,
+    model_id: &str,
+) -> ModelsResult<ModelInfo> {
+    // Send the request.
+    let response = client
+        .send(client.get(&format!(
+            "/v1/models/{}",
+            encode_path_segment(model_id)?
+        )))
+        .await?;
+
+    read_response(response).await
+}
@@ -0,0 +1,12 @@
+use crate::{ApiError, ClientError};
+
+/// The error type for the models API.
+#[derive(Debug, thiserror::Error)]
+pub enum ModelsError {
+    /// The client error.
+    #[error(transparent)]
+    ClientError(#[from] ClientError),
+    /// The API error.
+    #[error(transparent)]
+    ApiError(#[from] ApiError),
+}
@@ -0,0 +1,142 @@
+use crate::macros::{
+    impl_display_for_serialize, impl_enum_string_serialization,
+};
+use crate::messages::ClaudeModel;
+use std::fmt::Display;
+
+/// The information of a model available for the API key.
+///
+/// See also [the models API reference](https://docs.anthropic.com/en/api/models).
+///
+/// ## Example
+/// ```
+/// use clust::messages::ClaudeModel;
+/// use clust::models::ModelInfo;
+///
+/// let model_info = ModelInfo {
+///     id: "claude-3-haiku-20240307".to_string(),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(model_info.model(), ClaudeModel::Claude3Haiku20240307);
+/// ```
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct ModelInfo {
+    /// Object type. For models, this is always "model".
+    #[serde(rename = "type")]
+    pub _type: ModelObjectType,
+    /// Unique model identifier.
+    pub id: String,
+    /// A human-readable name for the model.
+    pub display_name: String,
+    /// RFC 3339 datetime string representing the time at which the model was released.
+    pub created_at: String,
+}
+
+impl_display_for_serialize!(ModelInfo);
+
+impl ModelInfo {
+    /// Returns the model identifier to use in the request body.
+    ///
+    /// A model unknown to this crate is [`ClaudeModel::Custom`].
+    pub fn model(&self) -> ClaudeModel {
+        ClaudeModel::from(self.id.as_str())
+    }
+}
+
+impl From<ModelInfo> for ClaudeModel {
+    fn from(model_info: ModelInfo) -> Self {
+        ClaudeModel::from(model_info.id)
+    }
+}
+
+impl From<&ModelInfo> for ClaudeModel {
+    fn from(model_info: &ModelInfo) -> Self {
+        model_info.model()
+    }
+}
+
+/// The object type of model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModelObjectType {
+    /// model
+    Model,
+}
+
+impl Default for ModelObjectType {
+    fn default() -> Self {
+        Self::Model
+    }
+}
+
+impl Display for ModelObjectType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ModelObjectType::Model => {
+                write!(f, "model")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ModelObjectType,
+    Model => "model"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Page;
+
+    #[test]
+    fn deserialize_page() {
+        let page = serde_json::from_str::<Page<ModelInfo>>(
+            r#"{"data":[{"type":"model","id":"claude-3-opus-20240229","display_name":"Claude 3 Opus","created_at":"2024-02-29T00:00:00Z"},{"type":"model","id":"claude-next","display_name":"Claude Next","created_at":"2025-01-01T00:00:00Z"}],"has_more":false,"first_id":"claude-3-opus-20240229","last_id":"claude-next"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(page.data.len(), 2);
+        assert_eq!(
+            page.data[0],
+            ModelInfo {
+                _type: ModelObjectType::Model,
+                id: "claude-3-opus-20240229".to_string(),
+                display_name: "Claude 3 Opus".to_string(),
+                created_at: "2024-02-29T00:00:00Z".to_string(),
+            }
+        );
+        assert!(!page.has_more);
+        assert_eq!(page.next_page_query(None), None);
+    }
+
+    #[test]
+    fn into_claude_model() {
+        let model_info = ModelInfo {
+            id: "claude-3-opus-20240229".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            model_info.model(),
+            ClaudeModel::Claude3Opus20240229
+        );
+        assert_eq!(
+            ClaudeModel::from(model_info),
+            ClaudeModel::Claude3Opus20240229
+        );
+
+        let model_info = ModelInfo {
+            id: "claude-next".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            ClaudeModel::from(&model_info),
+            ClaudeModel::Custom("claude-next".to_string())
+        );
+    }
+}
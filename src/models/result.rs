@@ -0,0 +1,4 @@
+use crate::models::ModelsError;
+
+/// The result type for the models API.
+pub type ModelsResult<T> = Result<T, ModelsError>;
@@ -0,0 +1,132 @@
+/// The query of the cursor pagination for the list APIs.
+///
+/// ## Example
+/// ```
+/// use clust::PaginationQuery;
+///
+/// let query = PaginationQuery {
+///     limit: Some(100),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct PaginationQuery {
+    /// The ID of the object to use as a cursor, which returns the page of results immediately before this object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+    /// The ID of the object to use as a cursor, which returns the page of results immediately after this object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+    /// The number of items to return per page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// The page of the list APIs.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct Page<T> {
+    /// The items of this page.
+    pub data: Vec<T>,
+    /// Whether there are more results in the requested direction.
+    pub has_more: bool,
+    /// The ID of the first item of this page, which is the cursor of the previous page.
+    pub first_id: Option<String>,
+    /// The ID of the last item of this page, which is the cursor of the next page.
+    pub last_id: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Returns the query of the next page, or `None` if this is the last page.
+    ///
+    /// ## Arguments
+    /// - `limit` - The number of items to return per page.
+    pub fn next_page_query(
+        &self,
+        limit: Option<u32>,
+    ) -> Option<PaginationQuery> {
+        if !self.has_more {
+            return None;
+        }
+
+        self.last_id
+            .as_ref()
+            .map(|last_id| PaginationQuery {
+                before_id: None,
+                after_id: Some(last_id.clone()),
+                limit,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_pagination_query() {
+        assert_eq!(
+            serde_json::to_string(&PaginationQuery::default()).unwrap(),
+            "{}"
+        );
+        assert_eq!(
+            serde_json::to_string(&PaginationQuery {
+                after_id: Some("id".to_string()),
+                limit: Some(20),
+                ..Default::default()
+            })
+            .unwrap(),
+            "{\"after_id\":\"id\",\"limit\":20}"
+        );
+    }
+
+    #[test]
+    fn deserialize_page() {
+        assert_eq!(
+            serde_json::from_str::<Page<String>>(
+                "{\"data\":[\"a\",\"b\"],\"has_more\":true,\"first_id\":\"a\",\"last_id\":\"b\"}"
+            )
+            .unwrap(),
+            Page {
+                data: vec!["a".to_string(), "b".to_string()],
+                has_more: true,
+                first_id: Some("a".to_string()),
+                last_id: Some("b".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn next_page_query() {
+        let page = Page {
+            data: vec!["a".to_string(), "b".to_string()],
+            has_more: true,
+            first_id: Some("a".to_string()),
+            last_id: Some("b".to_string()),
+        };
+        assert_eq!(
+            page.next_page_query(Some(2)),
+            Some(PaginationQuery {
+                before_id: None,
+                after_id: Some("b".to_string()),
+                limit: Some(2),
+            })
+        );
+
+        let page = Page {
+            has_more: false,
+            ..page
+        };
+        assert_eq!(page.next_page_query(Some(2)), None);
+    }
+}
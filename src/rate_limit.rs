@@ -0,0 +1,737 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+use crate::messages::MessagesRequestBody;
+use crate::retry::parse_retry_after;
+
+/// The rough number of tokens of a base64 encoded source, e.g. an image or a PDF.
+const ENCODED_SOURCE_TOKENS: u64 = 1600;
+
+/// The rough number of characters per token.
+const CHARACTERS_PER_TOKEN: u64 = 4;
+
+/// The upper bound of the delay by the `retry-after` header, within which the budgets per minute are fully replenished.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// The rate limits of the organization reported by the `anthropic-ratelimit-*` response headers.
+///
+/// See also [Rate limits](https://docs.anthropic.com/en/api/rate-limits#response-headers).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RateLimits {
+    /// The requests per minute.
+    pub requests: RateLimit,
+    /// The most restrictive of the input and output tokens per minute.
+    pub tokens: RateLimit,
+    /// The input tokens per minute.
+    pub input_tokens: RateLimit,
+    /// The output tokens per minute.
+    pub output_tokens: RateLimit,
+    /// The delay until the request can be retried, by the `retry-after` header.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimits {
+    /// Parses the rate limits from the response headers.
+    ///
+    /// ## Arguments
+    /// - `headers` - The response headers.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            requests: RateLimit::from_headers(headers, "requests"),
+            tokens: RateLimit::from_headers(headers, "tokens"),
+            input_tokens: RateLimit::from_headers(headers, "input-tokens"),
+            output_tokens: RateLimit::from_headers(headers, "output-tokens"),
+            retry_after: parse_retry_after(headers),
+        }
+    }
+}
+
+/// The rate limit of a kind of resources, e.g. requests or tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateLimit {
+    /// The maximum number of the resources allowed within the rate limit period.
+    pub limit: Option<u64>,
+    /// The number of the resources remaining before being rate limited.
+    pub remaining: Option<u64>,
+    /// The time when the rate limit will be fully replenished.
+    pub reset: Option<SystemTime>,
+}
+
+impl RateLimit {
+    /// Parses the rate limit from the `anthropic-ratelimit-{kind}-*` response headers.
+    ///
+    /// ## Arguments
+    /// - `headers` - The response headers.
+    /// - `kind` - The kind of the resources, e.g. `requests`.
+    fn from_headers(
+        headers: &HeaderMap,
+        kind: &str,
+    ) -> Self {
+        let header = |suffix: &str| {
+            headers
+                .get(format!(
+                    "anthropic-ratelimit-{}-{}",
+                    kind, suffix
+                ))?
+                .to_str()
+                .ok()
+                .map(str::trim)
+        };
+
+        Self {
+            limit: header("limit").and_then(|value| value.parse().ok()),
+            remaining: header("remaining").and_then(|value| value.parse().ok()),
+            reset: header("reset").and_then(parse_rfc3339),
+        }
+    }
+
+    /// Returns whether any of the rate limit headers is present.
+    pub fn is_present(&self) -> bool {
+        self.limit.is_some() || self.remaining.is_some() || self.reset.is_some()
+    }
+}
+
+/// The client-side rate limiter that makes callers wait instead of sending requests doomed to be rate limited.
+///
+/// It tracks the budgets of the requests, the input tokens and the output tokens per minute as token buckets replenished continuously,
+/// pre-estimates the cost of each request to create a message and updates the budgets by the `anthropic-ratelimit-*` and `retry-after` response headers.
+///
+/// The budgets not set are learned from the response headers. The clones share the same budgets.
+///
+/// ## NOTE
+/// Only the calls to create a message, i.e. [`Client::create_a_message`](crate::Client::create_a_message) and [`Client::create_a_message_stream`](crate::Client::create_a_message_stream) with their variants,
+/// are rate-limited before every attempt including retries.
+/// The other endpoints, e.g. counting tokens, message batches, models and text completions, have separate rate limits and are not tracked.
+///
+/// The input tokens are roughly estimated from the length of the request body and the output tokens are estimated by `max_tokens`,
+/// which are corrected by the response headers.
+///
+/// ## Example
+/// ```
+/// use clust::{Client, RateLimiter};
+///
+/// let rate_limiter = RateLimiter::new()
+///     .with_requests_per_minute(50)
+///     .with_input_tokens_per_minute(40_000)
+///     .with_output_tokens_per_minute(8_000);
+///
+/// let client = Client::from_api_key(clust::ApiKey::new("api-key"))
+///     .with_rate_limiter(rate_limiter);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<RateLimiterState>>,
+}
+
+/// The budgets shared by the clones of a rate limiter.
+#[derive(Debug, Default)]
+struct RateLimiterState {
+    /// The requests per minute.
+    requests: Bucket,
+    /// The input tokens per minute.
+    input_tokens: Bucket,
+    /// The output tokens per minute.
+    output_tokens: Bucket,
+    /// The time until which no request is sent, by the `retry-after` header.
+    blocked_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter learning the budgets from the response headers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the initial budget of the requests per minute.
+    ///
+    /// ## Arguments
+    /// - `limit` - The maximum number of requests per minute.
+    pub fn with_requests_per_minute(
+        self,
+        limit: u64,
+    ) -> Self {
+        self.state()
+            .requests
+            .set_limit(limit, Instant::now());
+        self
+    }
+
+    /// Sets the initial budget of the input tokens per minute.
+    ///
+    /// ## Arguments
+    /// - `limit` - The maximum number of input tokens per minute.
+    pub fn with_input_tokens_per_minute(
+        self,
+        limit: u64,
+    ) -> Self {
+        self.state()
+            .input_tokens
+            .set_limit(limit, Instant::now());
+        self
+    }
+
+    /// Sets the initial budget of the output tokens per minute.
+    ///
+    /// ## Arguments
+    /// - `limit` - The maximum number of output tokens per minute.
+    pub fn with_output_tokens_per_minute(
+        self,
+        limit: u64,
+    ) -> Self {
+        self.state()
+            .output_tokens
+            .set_limit(limit, Instant::now());
+        self
+    }
+
+    /// Waits until the budgets allow the request and consumes its cost.
+    ///
+    /// ## Arguments
+    /// - `cost` - The estimated cost of the request.
+    pub(crate) async fn acquire(
+        &self,
+        cost: &RequestCost,
+    ) {
+        loop {
+            let wait = self
+                .state()
+                .try_acquire(cost, Instant::now());
+            match wait {
+                | Some(wait) => tokio::time::sleep(wait).await,
+                | None => return,
+            }
+        }
+    }
+
+    /// Updates the budgets by the response headers.
+    ///
+    /// ## Arguments
+    /// - `headers` - The response headers.
+    pub(crate) fn update(
+        &self,
+        headers: &HeaderMap,
+    ) {
+        self.state().update(
+            &RateLimits::from_headers(headers),
+            Instant::now(),
+        );
+    }
+
+    /// Locks the state ignoring the poison, because the budgets are always consistent.
+    fn state(&self) -> MutexGuard<'_, RateLimiterState> {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl RateLimiterState {
+    /// Consumes the cost if the budgets allow it, or returns the time to wait.
+    fn try_acquire(
+        &mut self,
+        cost: &RequestCost,
+        now: Instant,
+    ) -> Option<Duration> {
+        let blocked = self
+            .blocked_until
+            .and_then(|blocked_until| blocked_until.checked_duration_since(now))
+            .unwrap_or_default();
+
+        self.requests.refill(now);
+        self.input_tokens.refill(now);
+        self.output_tokens.refill(now);
+
+        let wait = blocked
+            .max(self.requests.wait(1))
+            .max(self.input_tokens.wait(cost.input_tokens))
+            .max(self.output_tokens.wait(cost.output_tokens));
+        if !wait.is_zero() {
+            return Some(wait);
+        }
+
+        self.blocked_until = None;
+        self.requests.consume(1);
+        self.input_tokens.consume(cost.input_tokens);
+        self.output_tokens.consume(cost.output_tokens);
+        None
+    }
+
+    /// Updates the budgets by the rate limits of the response.
+    fn update(
+        &mut self,
+        rate_limits: &RateLimits,
+        now: Instant,
+    ) {
+        self.requests
+            .update(&rate_limits.requests, now);
+        self.input_tokens
+            .update(&rate_limits.input_tokens, now);
+        self.output_tokens
+            .update(&rate_limits.output_tokens, now);
+
+        // Ignore the delay overflowing the time, which is never reached.
+        let blocked_until = rate_limits
+            .retry_after
+            .and_then(|retry_after| {
+                now.checked_add(retry_after.min(MAX_RETRY_AFTER))
+            });
+        if let Some(blocked_until) = blocked_until {
+            self.blocked_until = Some(
+                self.blocked_until
+                    .map_or(blocked_until, |current| {
+                        current.max(blocked_until)
+                    }),
+            );
+        }
+    }
+}
+
+/// The budget replenished continuously up to the limit per minute.
+#[derive(Debug, Clone, Copy, Default)]
+struct Bucket {
+    /// The maximum per minute, or `None` if unknown and unlimited.
+    limit: Option<f64>,
+    /// The remaining budget.
+    available: f64,
+    /// The time when the remaining budget was updated.
+    updated: Option<Instant>,
+}
+
+impl Bucket {
+    /// Sets the limit, keeping the remaining budget within it.
+    fn set_limit(
+        &mut self,
+        limit: u64,
+        now: Instant,
+    ) {
+        let limit = limit as f64;
+        self.available = match self.limit {
+            | Some(_) => self.available.min(limit),
+            | None => limit,
+        };
+        self.limit = Some(limit);
+        self.updated.get_or_insert(now);
+    }
+
+    /// Replenishes the budget by the elapsed time.
+    fn refill(
+        &mut self,
+        now: Instant,
+    ) {
+        if let (Some(limit), Some(updated)) = (self.limit, self.updated) {
+            let elapsed = now
+                .saturating_duration_since(updated)
+                .as_secs_f64();
+            self.available =
+                (self.available + elapsed * limit / 60.0).min(limit);
+            self.updated = Some(now);
+        }
+    }
+
+    /// Returns the time to wait for the cost, which is capped by the limit not to wait forever.
+    fn wait(
+        &self,
+        cost: u64,
+    ) -> Duration {
+        match self.limit {
+            | Some(limit) if limit > 0.0 => {
+                let shortage = (cost as f64).min(limit) - self.available;
+                if shortage > 0.0 {
+                    Duration::from_secs_f64(shortage * 60.0 / limit)
+                } else {
+                    Duration::ZERO
+                }
+            },
+            | _ => Duration::ZERO,
+        }
+    }
+
+    /// Consumes the cost from the budget.
+    fn consume(
+        &mut self,
+        cost: u64,
+    ) {
+        if self.limit.is_some() {
+            self.available -= cost as f64;
+        }
+    }
+
+    /// Updates the budget by the rate limit of the response.
+    fn update(
+        &mut self,
+        rate_limit: &RateLimit,
+        now: Instant,
+    ) {
+        if let Some(limit) = rate_limit.limit {
+            self.set_limit(limit, now);
+        }
+        if let (Some(limit), Some(remaining)) = (self.limit, rate_limit.remaining)
+        {
+            self.available = (remaining as f64).min(limit);
+            self.updated = Some(now);
+        }
+    }
+}
+
+/// The estimated cost of a request to create a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct RequestCost {
+    /// The estimated input tokens.
+    pub(crate) input_tokens: u64,
+    /// The maximum output tokens.
+    pub(crate) output_tokens: u64,
+}
+
+impl RequestCost {
+    /// Estimates the cost of the request.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    pub(crate) fn estimate(request_body: &MessagesRequestBody) -> Self {
+        let input_tokens = serde_json::to_value(request_body)
+            .map_or(0, |value| estimate_tokens(&value));
+
+        Self {
+            input_tokens,
+            output_tokens: u64::from(request_body.max_tokens.value()),
+        }
+    }
+}
+
+/// Estimates the tokens of the strings in the JSON value.
+fn estimate_tokens(value: &serde_json::Value) -> u64 {
+    match value {
+        | serde_json::Value::String(text) => {
+            (text.chars().count() as u64).div_ceil(CHARACTERS_PER_TOKEN)
+        },
+        | serde_json::Value::Array(values) => values
+            .iter()
+            .map(estimate_tokens)
+            .sum(),
+        | serde_json::Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| match (key.as_str(), value) {
+                // The base64 encoded data of an image or a document.
+                | ("data", serde_json::Value::String(_)) => {
+                    ENCODED_SOURCE_TOKENS
+                },
+                | _ => estimate_tokens(value),
+            })
+            .sum(),
+        | _ => 0,
+    }
+}
+
+/// Parses the RFC 3339 timestamp, e.g. `2024-10-16T12:34:56.789Z`.
+fn parse_rfc3339(value: &str) -> Option<SystemTime> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        let digits = value.get(range)?;
+        if !digits
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        digits.parse().ok()
+    };
+
+    let year = number(0..4)?;
+    let month = number(5..7)?;
+    let day = number(8..10)?;
+    let hour = number(11..13)?;
+    let minute = number(14..16)?;
+    let second = number(17..19)?;
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // The fractional seconds.
+    let mut rest = &value[19..];
+    let mut nanos = 0u32;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let length = fraction
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if length == 0 {
+            return None;
+        }
+        for (index, digit) in fraction[..length.min(9)]
+            .bytes()
+            .enumerate()
+        {
+            nanos += u32::from(digit - b'0') * 10u32.pow(8 - index as u32);
+        }
+        rest = &fraction[length..];
+    }
+
+    // The offset from UTC in seconds.
+    let offset = match rest {
+        | "Z" | "z" => 0,
+        | _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                | b'+' => 1,
+                | b'-' => -1,
+                | _ => return None,
+            };
+            let hours: i64 = rest[1..3].parse().ok()?;
+            let minutes: i64 = rest[4..6].parse().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        },
+        | _ => return None,
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400
+        + hour * 3600
+        + minute * 60
+        + second
+        - offset;
+
+    UNIX_EPOCH.checked_add(Duration::new(
+        u64::try_from(seconds).ok()?,
+        nanos,
+    ))
+}
+
+/// Returns the number of days since the UNIX epoch of the proleptic Gregorian date.
+fn days_from_civil(
+    year: i64,
+    month: i64,
+    day: i64,
+) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100
+        + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rfc3339() {
+        assert_eq!(
+            super::parse_rfc3339("1970-01-01T00:00:00Z"),
+            Some(UNIX_EPOCH)
+        );
+        assert_eq!(
+            super::parse_rfc3339("2024-10-16T12:34:56Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1729082096))
+        );
+        assert_eq!(
+            super::parse_rfc3339("2024-10-16T12:34:56.25Z"),
+            Some(UNIX_EPOCH + Duration::from_millis(1729082096250))
+        );
+        assert_eq!(
+            super::parse_rfc3339("2024-10-16T21:34:56+09:00"),
+            Some(UNIX_EPOCH + Duration::from_secs(1729082096))
+        );
+        assert_eq!(
+            super::parse_rfc3339("2024-02-29T00:00:00Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1709164800))
+        );
+        assert_eq!(super::parse_rfc3339("2024-10-16"), None);
+        assert_eq!(
+            super::parse_rfc3339("2024-13-16T12:34:56Z"),
+            None
+        );
+        assert_eq!(
+            super::parse_rfc3339("2024-10-16T12:34:56"),
+            None
+        );
+    }
+
+    #[test]
+    fn from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "anthropic-ratelimit-requests-limit",
+            "50".parse().unwrap(),
+        );
+        headers.insert(
+            "anthropic-ratelimit-requests-remaining",
+            "49".parse().unwrap(),
+        );
+        headers.insert(
+            "anthropic-ratelimit-requests-reset",
+            "2024-10-16T12:34:56Z".parse().unwrap(),
+        );
+        headers.insert(
+            "anthropic-ratelimit-input-tokens-remaining",
+            "invalid".parse().unwrap(),
+        );
+        headers.insert(
+            "anthropic-ratelimit-output-tokens-limit",
+            "8000".parse().unwrap(),
+        );
+        headers.insert("retry-after", "5".parse().unwrap());
+
+        let rate_limits = RateLimits::from_headers(&headers);
+        assert_eq!(
+            rate_limits.requests,
+            RateLimit {
+                limit: Some(50),
+                remaining: Some(49),
+                reset: Some(UNIX_EPOCH + Duration::from_secs(1729082096)),
+            }
+        );
+        assert!(!rate_limits.tokens.is_present());
+        assert!(!rate_limits.input_tokens.is_present());
+        assert_eq!(
+            rate_limits.output_tokens.limit,
+            Some(8000)
+        );
+        assert_eq!(
+            rate_limits.retry_after,
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn try_acquire() {
+        let now = Instant::now();
+        let cost = RequestCost {
+            input_tokens: 100,
+            output_tokens: 4000,
+        };
+
+        // Unlimited without any budget.
+        let mut state = RateLimiterState::default();
+        assert_eq!(state.try_acquire(&cost, now), None);
+
+        // 1 request is replenished in 30 seconds.
+        let mut state = RateLimiterState::default();
+        state.requests.set_limit(2, now);
+        assert_eq!(state.try_acquire(&cost, now), None);
+        assert_eq!(state.try_acquire(&cost, now), None);
+        assert_eq!(
+            state.try_acquire(&cost, now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            state.try_acquire(&cost, now + Duration::from_secs(30)),
+            None
+        );
+
+        // 2000 output tokens remain and 2000 are replenished in 20 seconds.
+        let mut state = RateLimiterState::default();
+        state.output_tokens.set_limit(6000, now);
+        assert_eq!(state.try_acquire(&cost, now), None);
+        assert_eq!(
+            state.try_acquire(&cost, now),
+            Some(Duration::from_secs(20))
+        );
+
+        // The cost exceeding the limit waits only for the full budget.
+        let cost = RequestCost {
+            input_tokens: 100,
+            output_tokens: 10000,
+        };
+        assert_eq!(
+            state.try_acquire(&cost, now + Duration::from_secs(60)),
+            None
+        );
+    }
+
+    #[test]
+    fn update() {
+        let now = Instant::now();
+        let mut state = RateLimiterState::default();
+        let cost = RequestCost::default();
+
+        state.update(
+            &RateLimits {
+                requests: RateLimit {
+                    limit: Some(60),
+                    remaining: Some(0),
+                    reset: None,
+                },
+                ..Default::default()
+            },
+            now,
+        );
+        assert_eq!(
+            state.try_acquire(&cost, now),
+            Some(Duration::from_secs(1))
+        );
+
+        state.update(
+            &RateLimits {
+                retry_after: Some(Duration::from_secs(5)),
+                ..Default::default()
+            },
+            now,
+        );
+        assert_eq!(
+            state.try_acquire(&cost, now + Duration::from_secs(1)),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(
+            state.try_acquire(&cost, now + Duration::from_secs(5)),
+            None
+        );
+
+        // The huge delay is capped.
+        state.update(
+            &RateLimits {
+                retry_after: Some(Duration::MAX),
+                ..Default::default()
+            },
+            now,
+        );
+        assert_eq!(
+            state.try_acquire(&cost, now),
+            Some(MAX_RETRY_AFTER)
+        );
+    }
+
+    #[test]
+    fn estimate() {
+        use crate::messages::{ClaudeModel, MaxTokens, Message};
+
+        let request_body = MessagesRequestBody {
+            model: ClaudeModel::Claude3Haiku20240307,
+            messages: vec![Message::user("Hello, Claude!")],
+            max_tokens: MaxTokens::new(
+                16,
+                &ClaudeModel::Claude3Haiku20240307,
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+        let cost = RequestCost::estimate(&request_body);
+        assert!(cost.input_tokens > 0);
+        assert_eq!(cost.output_tokens, 16);
+
+        assert_eq!(
+            estimate_tokens(&serde_json::json!({
+                "text": "12345678",
+                "source": {"type": "base64", "data": "a".repeat(100_000)},
+            })),
+            2 + 2 + ENCODED_SOURCE_TOKENS
+        );
+    }
+}
@@ -0,0 +1,457 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use pin_project::pin_project;
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest::RequestBuilder;
+use tokio::sync::Notify;
+
+use crate::client_builder::RESERVED_HEADERS;
+use crate::messages::StreamError;
+use crate::{BetaFeatures, ClientError};
+
+/// The options of an API call in addition to the request body.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use clust::{CancellationToken, RequestOptions};
+///
+/// let cancellation_token = CancellationToken::new();
+/// let options = RequestOptions::new()
+///     .with_header("x-trace-id", "trace")
+///     .with_extra_body_field("undocumented_field", serde_json::json!(true))
+///     .with_timeout(Duration::from_secs(10))
+///     .with_cancellation_token(cancellation_token.clone());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// The extra headers of the request.
+    headers: Vec<(String, String)>,
+    /// The extra fields merged into the serialized request body.
+    extra_body: serde_json::Map<String, serde_json::Value>,
+    /// The total timeout of the request overriding the one of the client.
+    timeout: Option<Duration>,
+    /// The beta features of the request overriding the ones of the client.
+    beta_features: Option<BetaFeatures>,
+    /// The token to cancel the request.
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl RequestOptions {
+    /// Creates new empty request options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an extra header of the request, e.g. an idempotency key or a trace ID.
+    ///
+    /// The headers of the authentication, the API version and the beta features can not be overridden,
+    /// use [`RequestOptions::with_beta_features`] for the beta features.
+    ///
+    /// ## Arguments
+    /// - `name` - The header name.
+    /// - `value` - The header value.
+    pub fn with_header<N, V>(
+        mut self,
+        name: N,
+        value: V,
+    ) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.headers
+            .push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the extra fields merged into the serialized request body, which override the fields of the same names.
+    ///
+    /// ## Arguments
+    /// - `extra_body` - The JSON object of the extra fields.
+    pub fn with_extra_body(
+        mut self,
+        extra_body: serde_json::Map<String, serde_json::Value>,
+    ) -> Self {
+        self.extra_body = extra_body;
+        self
+    }
+
+    /// Adds an extra field merged into the serialized request body, which overrides the field of the same name.
+    ///
+    /// ## Arguments
+    /// - `name` - The field name.
+    /// - `value` - The field value.
+    pub fn with_extra_body_field<S>(
+        mut self,
+        name: S,
+        value: serde_json::Value,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.extra_body
+            .insert(name.into(), value);
+        self
+    }
+
+    /// Sets the total timeout of the request overriding the one of the client.
+    ///
+    /// ## Arguments
+    /// - `timeout` - The total timeout.
+    pub fn with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the beta features of the request overriding the ones of the client.
+    ///
+    /// ## Arguments
+    /// - `beta_features` - The beta features.
+    pub fn with_beta_features(
+        mut self,
+        beta_features: BetaFeatures,
+    ) -> Self {
+        self.beta_features = Some(beta_features);
+        self
+    }
+
+    /// Sets the token to cancel the request.
+    ///
+    /// A cancelled call returns [`ClientError::Cancelled`], and a cancelled stream ends with [`StreamError::Cancelled`].
+    ///
+    /// ## Arguments
+    /// - `cancellation_token` - The cancellation token.
+    pub fn with_cancellation_token(
+        mut self,
+        cancellation_token: CancellationToken,
+    ) -> Self {
+        self.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Returns the total timeout of the request.
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the beta features of the request.
+    pub(crate) fn beta_features(&self) -> Option<&BetaFeatures> {
+        self.beta_features.as_ref()
+    }
+
+    /// Applies the extra headers and the request body merged with the extra fields to the request.
+    ///
+    /// ## Arguments
+    /// - `request` - The request builder.
+    /// - `body` - The request body.
+    pub(crate) fn apply<T>(
+        &self,
+        mut request: RequestBuilder,
+        body: &T,
+    ) -> Result<RequestBuilder, ClientError>
+    where
+        T: serde::Serialize,
+    {
+        for (name, value) in &self.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| ClientError::InvalidHeader(name.clone()))?;
+            if RESERVED_HEADERS.contains(&header_name.as_str()) {
+                return Err(ClientError::ReservedHeader(name.clone()));
+            }
+            let header_value = HeaderValue::from_str(value)
+                .map_err(|_| ClientError::InvalidHeader(name.clone()))?;
+            request = request.header(header_name, header_value);
+        }
+
+        Ok(request.json(&self.merge_body(body)?))
+    }
+
+    /// Serializes the request body merged with the extra fields.
+    fn merge_body<T>(
+        &self,
+        body: &T,
+    ) -> Result<serde_json::Value, ClientError>
+    where
+        T: serde::Serialize,
+    {
+        let mut value = serde_json::to_value(body)
+            .map_err(ClientError::RequestBodySerializationFailed)?;
+
+        if let Some(object) = value.as_object_mut() {
+            for (name, field) in &self.extra_body {
+                object.insert(name.clone(), field.clone());
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Runs the future until it completes or the request is cancelled.
+    ///
+    /// ## Arguments
+    /// - `future` - The future of the API call.
+    pub(crate) async fn cancellable<F, T, E>(
+        &self,
+        future: F,
+    ) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<ClientError>,
+    {
+        let cancellation_token = match &self.cancellation_token {
+            | Some(cancellation_token) => cancellation_token,
+            | None => return future.await,
+        };
+
+        let mut future = std::pin::pin!(future);
+        let mut cancelled = std::pin::pin!(cancellation_token.cancelled());
+        std::future::poll_fn(|cx| {
+            if cancelled.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Err(ClientError::Cancelled.into()));
+            }
+            future.as_mut().poll(cx)
+        })
+        .await
+    }
+
+    /// Wraps the stream to end with the cancellation error when the request is cancelled.
+    ///
+    /// ## Arguments
+    /// - `stream` - The stream of the API call.
+    pub(crate) fn cancellable_stream<S, C>(
+        &self,
+        stream: S,
+    ) -> CancellableStream<S>
+    where
+        S: Stream<Item = Result<C, StreamError>>,
+    {
+        CancellableStream {
+            stream,
+            cancelled: self
+                .cancellation_token
+                .clone()
+                .map(|cancellation_token| {
+                    Box::pin(async move {
+                        cancellation_token
+                            .cancelled()
+                            .await
+                    }) as Pin<Box<dyn Future<Output = ()> + Send>>
+                }),
+            finished: false,
+        }
+    }
+}
+
+/// The token to cancel requests, which can be cloned and shared across tasks.
+///
+/// ## Example
+/// ```
+/// use clust::CancellationToken;
+///
+/// let cancellation_token = CancellationToken::new();
+/// let cloned = cancellation_token.clone();
+///
+/// cloned.cancel();
+/// assert!(cancellation_token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<CancellationState>,
+}
+
+/// The state shared by the clones of a cancellation token.
+#[derive(Debug, Default)]
+struct CancellationState {
+    /// Whether the token has been cancelled.
+    cancelled: AtomicBool,
+    /// Notifies the waiters of the cancellation.
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Creates a new cancellation token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the requests with this token.
+    pub fn cancel(&self) {
+        self.inner
+            .cancelled
+            .store(true, Ordering::SeqCst);
+        self.inner
+            .notify
+            .notify_waiters();
+    }
+
+    /// Returns whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner
+            .cancelled
+            .load(Ordering::SeqCst)
+    }
+
+    /// Waits until the token is cancelled.
+    pub async fn cancelled(&self) {
+        loop {
+            let mut notified =
+                std::pin::pin!(self.inner.notify.notified());
+            // Register the waiter before checking the flag not to miss the notification.
+            notified.as_mut().enable();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// The stream that ends with the cancellation error when the request is cancelled.
+#[pin_project]
+pub(crate) struct CancellableStream<S> {
+    #[pin]
+    stream: S,
+    /// The future completed when the request is cancelled.
+    cancelled: Option<Pin<Box<dyn Future<Output = ()> + Send>>>,
+    /// Whether the stream has ended by the cancellation.
+    finished: bool,
+}
+
+impl<S, C> Stream for CancellableStream<S>
+where
+    S: Stream<Item = Result<C, StreamError>>,
+{
+    type Item = Result<C, StreamError>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.finished {
+            return Poll::Ready(None);
+        }
+
+        if let Some(cancelled) = this.cancelled {
+            if cancelled.as_mut().poll(cx).is_ready() {
+                *this.finished = true;
+                return Poll::Ready(Some(Err(StreamError::Cancelled)));
+            }
+        }
+
+        this.stream.poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        let options = RequestOptions::new()
+            .with_header("x-trace-id", "trace")
+            .with_extra_body_field("max_tokens", serde_json::json!(16))
+            .with_extra_body_field("extra", serde_json::json!({"key": 1}));
+        let request = options
+            .apply(
+                reqwest::Client::new().post("http://localhost/v1/messages"),
+                &serde_json::json!({"model": "model", "max_tokens": 8}),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()["x-trace-id"], "trace");
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                request
+                    .body()
+                    .unwrap()
+                    .as_bytes()
+                    .unwrap()
+            )
+            .unwrap(),
+            serde_json::json!({"model": "model", "max_tokens": 16, "extra": {"key": 1}})
+        );
+
+        assert!(matches!(
+            RequestOptions::new()
+                .with_header("invalid header", "value")
+                .apply(
+                    reqwest::Client::new().post("http://localhost"),
+                    &serde_json::json!({})
+                ),
+            Err(ClientError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            RequestOptions::new()
+                .with_header("X-Api-Key", "key")
+                .apply(
+                    reqwest::Client::new().post("http://localhost"),
+                    &serde_json::json!({})
+                ),
+            Err(ClientError::ReservedHeader(_))
+        ));
+        assert!(matches!(
+            RequestOptions::new()
+                .with_header("anthropic-beta", "pdfs-2024-09-25")
+                .apply(
+                    reqwest::Client::new().post("http://localhost"),
+                    &serde_json::json!({})
+                ),
+            Err(ClientError::ReservedHeader(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn cancellable() {
+        let cancellation_token = CancellationToken::new();
+        let options = RequestOptions::new()
+            .with_cancellation_token(cancellation_token.clone());
+
+        let result: Result<u32, ClientError> =
+            options.cancellable(async { Ok(1) }).await;
+        assert_eq!(result.unwrap(), 1);
+
+        cancellation_token.cancel();
+        let result: Result<u32, ClientError> = options
+            .cancellable(std::future::pending())
+            .await;
+        assert!(matches!(result, Err(ClientError::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn cancellable_stream() {
+        use futures_util::StreamExt;
+
+        let cancellation_token = CancellationToken::new();
+        let options = RequestOptions::new()
+            .with_cancellation_token(cancellation_token.clone());
+
+        let mut stream = options.cancellable_stream(
+            futures_util::stream::iter(vec![
+                Ok::<u32, StreamError>(1),
+                Ok(2),
+            ]),
+        );
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+
+        cancellation_token.cancel();
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(StreamError::Cancelled))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+}
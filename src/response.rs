@@ -0,0 +1,163 @@
+use reqwest::header::HeaderMap;
+use reqwest::{Response, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::{ApiError, ClientError, RateLimits};
+
+/// The response of an API call with the metadata of the HTTP response.
+///
+/// See also [`Client::create_a_message_with_response`](crate::Client::create_a_message_with_response).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse<T> {
+    /// The metadata of the HTTP response.
+    pub metadata: ResponseMetadata,
+    /// The parsed response body.
+    pub body: T,
+}
+
+impl<T> ApiResponse<T> {
+    /// Returns the parsed response body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+/// The metadata of the HTTP response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseMetadata {
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// The unique ID of the request by the `request-id` header, which is useful to contact support.
+    pub request_id: Option<String>,
+    /// The rate limits of the organization by the `anthropic-ratelimit-*` headers.
+    pub rate_limits: RateLimits,
+}
+
+impl ResponseMetadata {
+    /// Creates the metadata from the HTTP response.
+    ///
+    /// ## Arguments
+    /// - `response` - The response of the API.
+    pub(crate) fn from_response(response: &Response) -> Self {
+        Self {
+            status: response.status(),
+            request_id: request_id(response.headers()),
+            rate_limits: RateLimits::from_headers(response.headers()),
+        }
+    }
+}
+
+/// Returns the unique ID of the request by the `request-id` response header.
+///
+/// ## Arguments
+/// - `headers` - The response headers.
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("request-id")?
+        .to_str()
+        .ok()
+        .map(str::to_string)
+}
+
+/// Read the response body as the object or the API error.
+///
+/// ## Arguments
+/// - `response` - The response of the API.
+pub(crate) async fn read_response<T, E>(response: Response) -> Result<T, E>
+where
+    T: DeserializeOwned,
+    E: From<ClientError> + From<ApiError>,
+{
+    read_response_with_metadata(response)
+        .await
+        .map(ApiResponse::into_body)
+}
+
+/// Read the response body as the object with the metadata or the API error.
+///
+/// ## Arguments
+/// - `response` - The response of the API.
+pub(crate) async fn read_response_with_metadata<T, E>(
+    response: Response
+) -> Result<ApiResponse<T>, E>
+where
+    T: DeserializeOwned,
+    E: From<ClientError> + From<ApiError>,
+{
+    // Error
+    if !response.status().is_success() {
+        return Err(read_error_response(response).await);
+    }
+
+    // Keep the metadata before consuming the response.
+    let metadata = ResponseMetadata::from_response(&response);
+
+    // Read the response text.
+    let response_text = response
+        .text()
+        .await
+        .map_err(ClientError::ReadResponseTextFailed)?;
+
+    // Deserialize the response.
+    let body = serde_json::from_str(&response_text).map_err(|error| {
+        ClientError::ResponseDeserializationFailed {
+            error,
+            text: response_text,
+        }
+    })?;
+
+    Ok(ApiResponse {
+        metadata,
+        body,
+    })
+}
+
+/// Read the response body of the failed status as the API error.
+///
+/// ## Arguments
+/// - `response` - The response of the API.
+pub(crate) async fn read_error_response<E>(response: Response) -> E
+where
+    E: From<ClientError> + From<ApiError>,
+{
+    // Keep the status and the request ID before consuming the response.
+    let status_code = response.status();
+    let request_id = request_id(response.headers());
+
+    // Read the response text.
+    let response_text = match response.text().await {
+        | Ok(response_text) => response_text,
+        | Err(error) => {
+            return ClientError::ReadResponseTextFailed(error).into();
+        },
+    };
+
+    // Deserialize the error response.
+    match serde_json::from_str(&response_text) {
+        | Ok(error_response) => {
+            ApiError::new(status_code, request_id, error_response).into()
+        },
+        | Err(error) => ClientError::ErrorResponseDeserializationFailed {
+            error,
+            text: response_text,
+        }
+        .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_id() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(super::request_id(&headers), None);
+
+        headers.insert("request-id", "req_01".parse().unwrap());
+        assert_eq!(
+            super::request_id(&headers),
+            Some("req_01".to_string())
+        );
+    }
+}
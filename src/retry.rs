@@ -0,0 +1,364 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+use crate::ApiErrorType;
+
+/// The retry policy of the API calling.
+///
+/// A failed request is retried with exponential backoff when the error is retryable,
+/// and the delay indicated by the `retry-after` response header takes precedence over the backoff if it is present, capped by `max_delay`.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use clust::RetryPolicy;
+///
+/// let retry_policy = RetryPolicy {
+///     max_attempts: 5,
+///     base_delay: Duration::from_millis(250),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts including the first one.
+    ///
+    /// `1` disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, which is doubled for each subsequent retry.
+    pub base_delay: Duration,
+    /// The upper bound of the backoff delay and the delay indicated by the `retry-after` header.
+    pub max_delay: Duration,
+    /// Whether to randomize the backoff delay in range: `[delay / 2, delay]` to avoid retrying in lockstep.
+    pub jitter: bool,
+    /// The API error types to retry.
+    pub retryable_api_errors: Vec<ApiErrorType>,
+    /// The kinds of HTTP request errors to retry.
+    pub retryable_http_errors: Vec<HttpErrorKind>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            jitter: true,
+            retryable_api_errors: vec![
+                ApiErrorType::RateLimitError,
+                ApiErrorType::ApiError,
+                ApiErrorType::OverloadedError,
+            ],
+            retryable_http_errors: vec![
+                HttpErrorKind::Connect,
+                HttpErrorKind::Timeout,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy that never retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the delay before the next attempt, or `None` if the result should not be retried.
+    ///
+    /// ## Arguments
+    /// - `attempt` - The number of attempts made so far, starting at `1`.
+    /// - `result` - The result of the last attempt.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        result: &Result<reqwest::Response, reqwest::Error>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        match result {
+            | Ok(response) => {
+                if !self.is_retryable_status(response.status()) {
+                    return None;
+                }
+
+                Some(
+                    parse_retry_after(response.headers())
+                        .map(|delay| delay.min(self.max_delay))
+                        .unwrap_or_else(|| self.backoff_delay(attempt)),
+                )
+            },
+            | Err(error) => {
+                if !self.is_retryable_error(error) {
+                    return None;
+                }
+
+                Some(self.backoff_delay(attempt))
+            },
+        }
+    }
+
+    /// Returns the delay before the next attempt after the read timeout, or `None` if it should not be retried.
+    ///
+    /// ## Arguments
+    /// - `attempt` - The number of attempts made so far, starting at `1`.
+    pub(crate) fn read_timeout_retry_delay(
+        &self,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts
+            || !self
+                .retryable_http_errors
+                .contains(&HttpErrorKind::Timeout)
+        {
+            return None;
+        }
+
+        Some(self.backoff_delay(attempt))
+    }
+
+    /// Returns whether the response status is retryable.
+    fn is_retryable_status(
+        &self,
+        status: StatusCode,
+    ) -> bool {
+        !status.is_success()
+            && self
+                .retryable_api_errors
+                .contains(&ApiErrorType::from(status))
+    }
+
+    /// Returns whether the HTTP request error is retryable.
+    fn is_retryable_error(
+        &self,
+        error: &reqwest::Error,
+    ) -> bool {
+        HttpErrorKind::from_error(error).map_or(false, |kind| {
+            self.retryable_http_errors
+                .contains(&kind)
+        })
+    }
+
+    /// Returns the backoff delay after the attempt.
+    fn backoff_delay(
+        &self,
+        attempt: u32,
+    ) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay / 2 + delay.mul_f64(random_fraction() / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+/// The kind of HTTP request error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpErrorKind {
+    /// Failed to connect to the server.
+    Connect,
+    /// The request timed out.
+    Timeout,
+    /// Failed to send the request.
+    Request,
+    /// Failed to read the response body.
+    Body,
+}
+
+impl HttpErrorKind {
+    /// Classifies the HTTP request error.
+    pub fn from_error(error: &reqwest::Error) -> Option<Self> {
+        if error.is_timeout() {
+            Some(Self::Timeout)
+        } else if error.is_connect() {
+            Some(Self::Connect)
+        } else if error.is_body() {
+            Some(Self::Body)
+        } else if error.is_request() {
+            Some(Self::Request)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the delay from the `retry-after` header in seconds.
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get("retry-after")?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+}
+
+/// Returns a random number in range: `[0.0, 1.0)`.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos()),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default() {
+        let retry_policy = RetryPolicy::default();
+        assert_eq!(retry_policy.max_attempts, 3);
+        assert!(retry_policy.jitter);
+    }
+
+    #[test]
+    fn disabled() {
+        assert_eq!(
+            RetryPolicy::disabled().max_attempts,
+            1
+        );
+    }
+
+    #[test]
+    fn is_retryable_status() {
+        let retry_policy = RetryPolicy::default();
+        assert!(retry_policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(
+            retry_policy.is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        assert!(retry_policy
+            .is_retryable_status(StatusCode::from_u16(529).unwrap()));
+        assert!(!retry_policy.is_retryable_status(StatusCode::BAD_REQUEST));
+        assert!(!retry_policy.is_retryable_status(StatusCode::OK));
+
+        let retry_policy = RetryPolicy {
+            retryable_api_errors: vec![ApiErrorType::Unknown(
+                StatusCode::BAD_GATEWAY,
+            )],
+            ..Default::default()
+        };
+        assert!(retry_policy.is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(
+            !retry_policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS)
+        );
+    }
+
+    #[test]
+    fn backoff_delay() {
+        let retry_policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            retry_policy.backoff_delay(1),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            retry_policy.backoff_delay(2),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            retry_policy.backoff_delay(3),
+            Duration::from_millis(350)
+        );
+        assert_eq!(
+            retry_policy.backoff_delay(100),
+            Duration::from_millis(350)
+        );
+    }
+
+    #[test]
+    fn backoff_delay_with_jitter() {
+        let retry_policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            jitter: true,
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            let delay = retry_policy.backoff_delay(2);
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn read_timeout_retry_delay() {
+        let retry_policy = RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            retry_policy.read_timeout_retry_delay(1),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            retry_policy.read_timeout_retry_delay(3),
+            None
+        );
+
+        let retry_policy = RetryPolicy {
+            retryable_http_errors: vec![HttpErrorKind::Connect],
+            ..Default::default()
+        };
+        assert_eq!(
+            retry_policy.read_timeout_retry_delay(1),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert("retry-after", "3".parse().unwrap());
+        assert_eq!(
+            parse_retry_after(&headers),
+            Some(Duration::from_secs(3))
+        );
+
+        headers.insert("retry-after", "0.5".parse().unwrap());
+        assert_eq!(
+            parse_retry_after(&headers),
+            Some(Duration::from_millis(500))
+        );
+
+        headers.insert(
+            "retry-after",
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert("retry-after", "-1".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert("retry-after", "1e30".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert("retry-after", "NaN".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+}
@@ -0,0 +1,26 @@
+//! The decoder of [Server-Sent Events](https://html.spec.whatwg.org/multipage/server-sent-events.html).
+//!
+//! It follows the event stream interpretation of the WHATWG HTML Living Standard,
+//! and is independent of the Anthropic API so that it can decode any byte stream.
+//!
+//! ## Example
+//! ```
+//! use clust::sse::SseDecoder;
+//!
+//! let mut decoder = SseDecoder::new();
+//! decoder.push(b"event: ping\r\ndata: {\"type\"");
+//! assert_eq!(decoder.next_event(), None);
+//!
+//! decoder.push(b": \"ping\"}\r\n\r\n");
+//! let event = decoder.next_event().unwrap();
+//! assert_eq!(event.event, "ping");
+//! assert_eq!(event.data, "{\"type\": \"ping\"}");
+//! ```
+
+mod decoder;
+mod event;
+mod event_stream;
+
+pub use decoder::SseDecoder;
+pub use event::SseEvent;
+pub use event_stream::SseStream;
@@ -0,0 +1,546 @@
+use std::time::Duration;
+
+use bytes::{Buf, BytesMut};
+
+use crate::sse::SseEvent;
+
+/// The byte order mark of UTF-8.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// The incremental decoder of [Server-Sent Events](https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation).
+///
+/// Bytes can be pushed in arbitrary fragments, and events are dispatched when a blank line arrives.
+/// It supports:
+/// - Lines terminated by CRLF, LF or CR, even if CRLF is split across fragments.
+/// - A leading byte order mark.
+/// - Comment lines starting with `:`.
+/// - Fields without a value or without a space after the colon.
+/// - Multiple `data` fields joined by a line feed.
+/// - The `id` and `retry` fields.
+///
+/// An incomplete event at the end of the stream is discarded as the specification.
+#[derive(Debug, Clone, Default)]
+pub struct SseDecoder {
+    /// The bytes that have not been decoded yet.
+    buffer: BytesMut,
+    /// The length of the buffer already scanned for a line terminator.
+    ///
+    /// It keeps the decoding linear in time even if a long line arrives in many fragments.
+    scanned: usize,
+    /// Whether the leading byte order mark has been checked.
+    bom_checked: bool,
+    /// Whether the last line was terminated by CR, so a following LF must be skipped.
+    skip_line_feed: bool,
+    /// The fields of the event being decoded.
+    fields: EventFields,
+}
+
+impl SseDecoder {
+    /// Creates a new decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes bytes of the stream to the decoder.
+    ///
+    /// ## Arguments
+    /// - `bytes` - The bytes of the stream.
+    pub fn push(
+        &mut self,
+        bytes: &[u8],
+    ) {
+        self.buffer
+            .extend_from_slice(bytes);
+    }
+
+    /// Decodes the next event from the pushed bytes.
+    ///
+    /// It returns `None` if no complete event has arrived yet.
+    pub fn next_event(&mut self) -> Option<SseEvent> {
+        if !self.check_bom() {
+            return None;
+        }
+
+        loop {
+            if self.skip_line_feed && !self.buffer.is_empty() {
+                if self.buffer[0] == b'\n' {
+                    self.buffer.advance(1);
+                }
+                self.skip_line_feed = false;
+            }
+
+            // Scan only the bytes which have not been scanned yet.
+            let end = match self.buffer[self.scanned..]
+                .iter()
+                .position(|b| *b == b'\n' || *b == b'\r')
+            {
+                | Some(position) => self.scanned + position,
+                | None => {
+                    self.scanned = self.buffer.len();
+                    return None;
+                },
+            };
+
+            // Line terminators never appear in a multibyte sequence, so a line can be decoded independently.
+            // The line is borrowed from the buffer without copying unless it is invalid as UTF-8.
+            let line = &self.buffer[..end];
+            let event = match std::str::from_utf8(line) {
+                | Ok(line) => self.fields.process_line(line),
+                | Err(_) => self
+                    .fields
+                    .process_line(&String::from_utf8_lossy(line)),
+            };
+
+            self.skip_line_feed = self.buffer[end] == b'\r';
+            self.buffer.advance(end + 1);
+            self.scanned = 0;
+
+            if event.is_some() {
+                return event;
+            }
+        }
+    }
+
+    /// Returns the last event ID of the dispatched events.
+    pub fn last_event_id(&self) -> &str {
+        &self.fields.last_event_id
+    }
+
+    /// Returns the reconnection time specified by the `retry` field.
+    pub fn retry(&self) -> Option<Duration> {
+        self.fields.retry
+    }
+
+    /// Removes the leading byte order mark and returns whether the buffer is ready to decode.
+    fn check_bom(&mut self) -> bool {
+        if self.bom_checked {
+            return true;
+        }
+
+        // Wait until the buffer is long enough to tell whether it starts with the byte order mark.
+        if self.buffer.len() < BOM.len() && BOM.starts_with(&self.buffer) {
+            return false;
+        }
+        if self.buffer.starts_with(BOM) {
+            self.buffer.advance(BOM.len());
+        }
+        self.bom_checked = true;
+
+        true
+    }
+}
+
+/// The fields of the event being decoded.
+#[derive(Debug, Clone, Default)]
+struct EventFields {
+    /// The event type buffer.
+    event_type: String,
+    /// The data buffer.
+    data: String,
+    /// The last event ID buffer.
+    last_event_id_buffer: String,
+    /// The last event ID of the dispatched event.
+    last_event_id: String,
+    /// The reconnection time.
+    retry: Option<Duration>,
+}
+
+impl EventFields {
+    /// Processes the line and returns the event if it is dispatched.
+    fn process_line(
+        &mut self,
+        line: &str,
+    ) -> Option<SseEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+
+        // Comment line.
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.find(':') {
+            | Some(colon) => {
+                let value = &line[colon + 1..];
+                (
+                    &line[..colon],
+                    value
+                        .strip_prefix(' ')
+                        .unwrap_or(value),
+                )
+            },
+            | None => (line, ""),
+        };
+
+        match field {
+            | "event" => {
+                self.event_type.clear();
+                self.event_type.push_str(value);
+            },
+            | "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            },
+            | "id" => {
+                if !value.contains('\0') {
+                    self.last_event_id_buffer.clear();
+                    self.last_event_id_buffer.push_str(value);
+                }
+            },
+            | "retry" => {
+                if !value.is_empty()
+                    && value
+                        .bytes()
+                        .all(|b| b.is_ascii_digit())
+                {
+                    if let Ok(milliseconds) = value.parse::<u64>() {
+                        self.retry = Some(Duration::from_millis(milliseconds));
+                    }
+                }
+            },
+            // Unknown fields are ignored.
+            | _ => {},
+        }
+
+        None
+    }
+
+    /// Dispatches the buffered event.
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        if self.last_event_id != self.last_event_id_buffer {
+            self.last_event_id = self
+                .last_event_id_buffer
+                .clone();
+        }
+
+        let event_type = std::mem::take(&mut self.event_type);
+        let mut data = std::mem::take(&mut self.data);
+        if data.is_empty() {
+            return None;
+        }
+
+        if data.ends_with('\n') {
+            data.pop();
+        }
+
+        Some(SseEvent {
+            event: if event_type.is_empty() {
+                "message".to_string()
+            } else {
+                event_type
+            },
+            data,
+            id: self.last_event_id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(fragments: &[&[u8]]) -> Vec<SseEvent> {
+        let mut decoder = SseDecoder::new();
+        let mut events = Vec::new();
+        for fragment in fragments {
+            decoder.push(fragment);
+            while let Some(event) = decoder.next_event() {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    #[test]
+    fn line_feed() {
+        assert_eq!(
+            decode(&[b"event: ping\ndata: {}\n\n"]),
+            vec![SseEvent::new("ping", "{}")]
+        );
+    }
+
+    #[test]
+    fn carriage_return_line_feed() {
+        assert_eq!(
+            decode(&[b"event: ping\r\ndata: {}\r\n\r\n"]),
+            vec![SseEvent::new("ping", "{}")]
+        );
+    }
+
+    #[test]
+    fn carriage_return() {
+        assert_eq!(
+            decode(&[b"event: ping\rdata: {}\r\r"]),
+            vec![SseEvent::new("ping", "{}")]
+        );
+    }
+
+    #[test]
+    fn carriage_return_line_feed_split_across_fragments() {
+        assert_eq!(
+            decode(&[
+                b"event: ping\r",
+                b"\ndata: {}\r",
+                b"\n\r",
+                b"\n"
+            ]),
+            vec![SseEvent::new("ping", "{}")]
+        );
+    }
+
+    #[test]
+    fn mixed_line_terminators() {
+        assert_eq!(
+            decode(&[b"data: a\r\ndata: b\rdata: c\n\r\n"]),
+            vec![SseEvent::new("message", "a\nb\nc")]
+        );
+    }
+
+    #[test]
+    fn byte_by_byte() {
+        let source = b"event: ping\r\ndata: {\"type\": \"ping\"}\r\n\r\n";
+        let fragments = source
+            .chunks(1)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decode(&fragments),
+            vec![SseEvent::new(
+                "ping",
+                "{\"type\": \"ping\"}"
+            )]
+        );
+    }
+
+    #[test]
+    fn comment() {
+        assert_eq!(
+            decode(&[b": keep-alive\n\n:\nevent: ping\n: comment\ndata: {}\n\n"]),
+            vec![SseEvent::new("ping", "{}")]
+        );
+    }
+
+    #[test]
+    fn multi_line_data() {
+        assert_eq!(
+            decode(&[b"data: first\ndata: second\ndata:\ndata: third\n\n"]),
+            vec![SseEvent::new(
+                "message",
+                "first\nsecond\n\nthird"
+            )]
+        );
+    }
+
+    #[test]
+    fn without_space_after_colon() {
+        assert_eq!(
+            decode(&[b"event:ping\ndata:{}\n\n"]),
+            vec![SseEvent::new("ping", "{}")]
+        );
+    }
+
+    #[test]
+    fn only_one_leading_space_is_removed() {
+        assert_eq!(
+            decode(&[b"data:  two spaces \n\n"]),
+            vec![SseEvent::new("message", " two spaces ")]
+        );
+    }
+
+    #[test]
+    fn colon_in_value() {
+        assert_eq!(
+            decode(&[b"data: {\"key\": \"value\"}\n\n"]),
+            vec![SseEvent::new(
+                "message",
+                "{\"key\": \"value\"}"
+            )]
+        );
+    }
+
+    #[test]
+    fn field_without_colon() {
+        assert_eq!(
+            decode(&[b"data\ndata\n\n"]),
+            vec![SseEvent::new("message", "\n")]
+        );
+        assert_eq!(
+            decode(&[b"event\ndata: a\n\n"]),
+            vec![SseEvent::new("message", "a")]
+        );
+    }
+
+    #[test]
+    fn default_event_type() {
+        assert_eq!(
+            decode(&[b"data: a\n\nevent: ping\ndata: b\n\ndata: c\n\n"]),
+            vec![
+                SseEvent::new("message", "a"),
+                SseEvent::new("ping", "b"),
+                SseEvent::new("message", "c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn event_without_data_is_not_dispatched() {
+        assert_eq!(
+            decode(&[b"event: ping\n\ndata: a\n\n"]),
+            vec![SseEvent::new("message", "a")]
+        );
+    }
+
+    #[test]
+    fn field_names_are_case_sensitive() {
+        assert_eq!(
+            decode(&[b"Event: ping\nDATA: a\ndata: b\n\n"]),
+            vec![SseEvent::new("message", "b")]
+        );
+    }
+
+    #[test]
+    fn unknown_field() {
+        assert_eq!(
+            decode(&[b"foo: bar\ndata: a\n\n"]),
+            vec![SseEvent::new("message", "a")]
+        );
+    }
+
+    #[test]
+    fn id() {
+        let mut decoder = SseDecoder::new();
+        decoder.push(b"id: 1\ndata: a\n\ndata: b\n\nid\ndata: c\n\n");
+
+        assert_eq!(
+            decoder.next_event().unwrap().id,
+            "1"
+        );
+        assert_eq!(decoder.last_event_id(), "1");
+        // The last event ID persists across events.
+        assert_eq!(
+            decoder.next_event().unwrap().id,
+            "1"
+        );
+        // An empty ID resets the last event ID.
+        assert_eq!(
+            decoder.next_event().unwrap().id,
+            ""
+        );
+        assert_eq!(decoder.last_event_id(), "");
+    }
+
+    #[test]
+    fn id_with_null_is_ignored() {
+        let mut decoder = SseDecoder::new();
+        decoder.push(b"id: 1\ndata: a\n\nid: 2\0\ndata: b\n\n");
+
+        assert_eq!(
+            decoder.next_event().unwrap().id,
+            "1"
+        );
+        assert_eq!(
+            decoder.next_event().unwrap().id,
+            "1"
+        );
+    }
+
+    #[test]
+    fn id_without_data_updates_last_event_id() {
+        let mut decoder = SseDecoder::new();
+        decoder.push(b"id: 1\n\n");
+
+        assert_eq!(decoder.next_event(), None);
+        assert_eq!(decoder.last_event_id(), "1");
+    }
+
+    #[test]
+    fn retry() {
+        let mut decoder = SseDecoder::new();
+        decoder.push(b"retry: 1500\n\n");
+        assert_eq!(decoder.next_event(), None);
+        assert_eq!(
+            decoder.retry(),
+            Some(Duration::from_millis(1500))
+        );
+
+        decoder.push(b"retry: 1.5\nretry: -1\nretry: abc\nretry:\n\n");
+        assert_eq!(decoder.next_event(), None);
+        assert_eq!(
+            decoder.retry(),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        assert_eq!(
+            decode(&[b"\xEF\xBB\xBFdata: a\n\n"]),
+            vec![SseEvent::new("message", "a")]
+        );
+        assert_eq!(
+            decode(&[b"\xEF", b"\xBB", b"\xBFdata: a\n\n"]),
+            vec![SseEvent::new("message", "a")]
+        );
+        // Only the leading byte order mark is removed.
+        assert_eq!(
+            decode(&[b"data: a\n\n\xEF\xBB\xBFdata: b\n\n"]),
+            vec![SseEvent::new("message", "a")]
+        );
+    }
+
+    #[test]
+    fn utf8() {
+        let source = "data: こんにちは\n\n".as_bytes();
+        let (first, second) = source.split_at(8);
+        assert_eq!(
+            decode(&[first, second]),
+            vec![SseEvent::new("message", "こんにちは")]
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_is_replaced() {
+        assert_eq!(
+            decode(&[b"data: a\xFFb\n\n"]),
+            vec![SseEvent::new("message", "a\u{FFFD}b")]
+        );
+    }
+
+    #[test]
+    fn incomplete_event_is_discarded() {
+        assert_eq!(
+            decode(&[b"data: a\n\ndata: b\n"]),
+            vec![SseEvent::new("message", "a")]
+        );
+        assert_eq!(
+            decode(&[b"data: a\n\ndata: b"]),
+            vec![SseEvent::new("message", "a")]
+        );
+    }
+
+    #[test]
+    fn long_line_in_many_fragments() {
+        let data = "x".repeat(1024 * 1024);
+        let source = format!("data: {}\n\n", data);
+        let fragments = source
+            .as_bytes()
+            .chunks(7)
+            .collect::<Vec<_>>();
+
+        let mut decoder = SseDecoder::new();
+        let mut events = Vec::new();
+        for fragment in fragments {
+            decoder.push(fragment);
+            assert!(decoder.scanned <= decoder.buffer.len());
+            while let Some(event) = decoder.next_event() {
+                events.push(event);
+            }
+        }
+
+        assert_eq!(
+            events,
+            vec![SseEvent::new("message", data)]
+        );
+        assert!(decoder.buffer.is_empty());
+    }
+}
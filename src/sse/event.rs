@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+/// The event dispatched by the Server-Sent Events decoder.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SseEvent {
+    /// The event type, which is `message` if the `event` field is not specified.
+    pub event: String,
+    /// The data joined by a line feed for each `data` field.
+    pub data: String,
+    /// The last event ID, which persists across events until the next `id` field.
+    pub id: String,
+}
+
+impl Default for SseEvent {
+    fn default() -> Self {
+        Self {
+            event: "message".to_string(),
+            data: String::new(),
+            id: String::new(),
+        }
+    }
+}
+
+impl Display for SseEvent {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "event: {}", self.event)?;
+        for line in self.data.split('\n') {
+            write!(f, "\ndata: {}", line)?;
+        }
+        if !self.id.is_empty() {
+            write!(f, "\nid: {}", self.id)?;
+        }
+        Ok(())
+    }
+}
+
+impl SseEvent {
+    /// Creates a new event.
+    ///
+    /// ## Arguments
+    /// - `event` - The event type.
+    /// - `data` - The event data.
+    pub fn new<S, T>(
+        event: S,
+        data: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            event: event.into(),
+            data: data.into(),
+            id: String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            SseEvent::default(),
+            SseEvent::new("message", "")
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            SseEvent::new("ping", "{\"type\": \"ping\"}").to_string(),
+            "event: ping\ndata: {\"type\": \"ping\"}"
+        );
+
+        let event = SseEvent {
+            event: "message".to_string(),
+            data: "first\nsecond".to_string(),
+            id: "1".to_string(),
+        };
+        assert_eq!(
+            event.to_string(),
+            "event: message\ndata: first\ndata: second\nid: 1"
+        );
+    }
+}
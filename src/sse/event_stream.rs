@@ -0,0 +1,135 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::{ready, Stream};
+use pin_project::pin_project;
+
+use crate::sse::{SseDecoder, SseEvent};
+
+/// The stream of Server-Sent Events decoded from a stream of bytes.
+///
+/// ## Example
+/// ```no_run
+/// use clust::sse::SseStream;
+/// use futures_util::StreamExt;
+///
+/// # async fn example() -> anyhow::Result<()> {
+/// let response = reqwest::get("https://example.com/events").await?;
+/// let mut stream = SseStream::new(response.bytes_stream());
+/// while let Some(event) = stream.next().await {
+///     let event = event?;
+///     println!("{}: {}", event.event, event.data);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[pin_project]
+pub struct SseStream<S> {
+    #[pin]
+    stream: S,
+    decoder: SseDecoder,
+}
+
+impl<S> SseStream<S> {
+    /// Creates a new stream of events.
+    ///
+    /// ## Arguments
+    /// - `stream` - The stream of bytes.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            decoder: SseDecoder::new(),
+        }
+    }
+
+    /// Returns the decoder, e.g. to get the last event ID to reconnect.
+    pub fn decoder(&self) -> &SseDecoder {
+        &self.decoder
+    }
+}
+
+impl<S, B, E> Stream for SseStream<S>
+where
+    S: Stream<Item = Result<B, E>>,
+    B: AsRef<[u8]>,
+{
+    type Item = Result<SseEvent, E>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(event) = this.decoder.next_event() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            match ready!(this
+                .stream
+                .as_mut()
+                .poll_next(cx))
+            {
+                | Some(Ok(bytes)) => {
+                    this.decoder.push(bytes.as_ref());
+                },
+                | Some(Err(error)) => {
+                    return Poll::Ready(Some(Err(error)));
+                },
+                // An incomplete event at the end of the stream is discarded.
+                | None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn next() {
+        use futures_util::StreamExt;
+
+        let input_stream = futures_util::stream::iter(vec![
+            Ok::<&[u8], ()>(b"event: ping\r\ndata: {\"type\""),
+            Ok::<&[u8], ()>(b": \"ping\"}\r\n\r\n: comment\r\ndata: a\r"),
+            Ok::<&[u8], ()>(b"\ndata: b\r\n\r\ndata: incomplete\r\n"),
+        ]);
+
+        let mut stream = SseStream::new(input_stream);
+        assert_eq!(
+            stream.next().await,
+            Some(Ok(SseEvent::new(
+                "ping",
+                "{\"type\": \"ping\"}"
+            )))
+        );
+        assert_eq!(
+            stream.next().await,
+            Some(Ok(SseEvent::new("message", "a\nb")))
+        );
+        assert_eq!(stream.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn next_with_error() {
+        use futures_util::StreamExt;
+
+        let input_stream = futures_util::stream::iter(vec![
+            Ok::<&[u8], &str>(b"data: a\n\n"),
+            Err("error"),
+        ]);
+
+        let mut stream = SseStream::new(input_stream);
+        assert_eq!(
+            stream.next().await,
+            Some(Ok(SseEvent::new("message", "a")))
+        );
+        assert_eq!(
+            stream.next().await,
+            Some(Err("error"))
+        );
+    }
+}